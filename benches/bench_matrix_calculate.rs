@@ -169,6 +169,33 @@ fn bench_matrix_product_1000x1000(c: &mut Criterion) {
     });
 }
 
+fn bench_matrix_par_product_100x100(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Matrix product");
+    let a = Matrix::<100, 100, f64>::random();
+    let b = Matrix::<100, 100, f64>::random();
+
+    group.bench_function("100x100 parallel", |bencher| {
+        bencher.iter_batched(
+            || (a.clone(), b.clone()),
+            |(x, y)| x.par_product(&y),
+            criterion::BatchSize::SmallInput,
+        );
+    });
+}
+
+fn bench_matrix_par_product_300x300(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Matrix product");
+    let a = Box::new(Matrix::<300, 300, f64>::random());
+    let b = Box::new(Matrix::<300, 300, f64>::random());
+
+    group.bench_function("300x300", |bencher| {
+        bencher.iter(|| a.product(&b));
+    });
+    group.bench_function("300x300 parallel", |bencher| {
+        bencher.iter(|| a.par_product(&b));
+    });
+}
+
 fn bench_matrix_det_10x10(c: &mut Criterion) {
     let mut group = c.benchmark_group("Matrix determinant");
     let a = Matrix::<10, 10, f64>::random();
@@ -208,6 +235,12 @@ criterion_group!(
    // bench_matrix_sub_1000x1000
 );
 criterion_group!(benches_mul, bench_matrix_mul_10x10, bench_matrix_mul_100x100);
-criterion_group!(benches_product, bench_matrix_product_10x10, bench_matrix_product_100x100);
+criterion_group!(
+    benches_product,
+    bench_matrix_product_10x10,
+    bench_matrix_product_100x100,
+    bench_matrix_par_product_100x100,
+    bench_matrix_par_product_300x300,
+);
 criterion_group!(benches_det, bench_matrix_det_10x10, bench_matrix_det_100x100);
 criterion_main!(benches_add, bences_sub, benches_mul, benches_product, benches_det);
@@ -1,8 +1,36 @@
+mod cholesky;
+mod compare;
+mod complex;
+mod dynamic;
+mod eigen;
 pub mod error;
+mod exponential;
+mod format;
 mod initial;
+mod io;
+mod iter;
+mod lu;
 pub mod operation;
+mod qr;
+#[cfg(feature = "serde")]
+mod serialize;
 mod shape;
+mod solve;
+mod statistics;
+mod svd;
 mod util;
+mod view;
+
+pub use cholesky::CholeskyDecomposition;
+pub use dynamic::DMatrix;
+pub use eigen::SymmetricEigen;
+pub use format::MatrixFormat;
+pub use io::IoElement;
+pub use lu::LUDecomposition;
+pub use num_complex::Complex;
+pub use qr::QRDecomposition;
+pub use svd::SVD;
+pub use view::{MatrixView, MatrixViewMut};
 
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::{Add, Mul, Not, Sub};
@@ -27,47 +55,6 @@ where
     }
 }
 
-impl<const ROWS: usize, const COLS: usize, T> Matrix<ROWS, COLS, T>
-where
-    T: Display + Copy + Send + Sync,
-{
-    fn display(&self) -> String {
-        let mut digits: u8 = 0;
-        for value in self.data.iter() {
-            let list = *value;
-            for f in list.iter() {
-                let d = f.to_string().len() as u8;
-                if d > digits {
-                    digits = d
-                }
-            }
-        }
-        if ROWS == 0 || COLS == 0 {
-            return "┌┐\n└┘\n".to_string();
-        } else if ROWS == 1 {
-            let line = util::print_single_line(&self.data, digits);
-            return line;
-        }
-        let mut result = String::new();
-        for row in 0..ROWS {
-            let mut line = String::new();
-            let (start_char, end_char) = util::get_boundary_char(row, ROWS);
-            line.push_str(&start_char);
-            let start = row * COLS;
-            let end = (row + 1) * COLS;
-            for i in start..end {
-                let value = self.data[row][i - start];
-                let str = format!("{:<digit$}", value, digit = (digits + 2) as usize);
-                line.push_str(&str);
-            }
-            line.push_str(&end_char);
-            line.push('\n');
-            result.push_str(&line);
-        }
-        result
-    }
-}
-
 
 impl<const ROWS: usize, const COLS: usize, T> Matrix<ROWS, COLS, T>
 where
@@ -94,8 +81,9 @@ where
 
     pub fn set(&mut self, x: usize, y: usize, value: T) -> Result<(), error::OperationError> {
         if x >= COLS || y >= ROWS {
-            return Err(error::OperationError {
-                message: "index out of bounds".to_string(),
+            return Err(error::OperationError::IndexOutOfBounds {
+                index: (y, x),
+                shape: (ROWS, COLS),
             });
         }
         self.data[y][x] = value;
@@ -103,23 +91,72 @@ where
     }
 }
 
+// 可以求行列式的元素类型：浮点走 O(n³) 的 LU 分解，整数保留按置换展开的精确算法
+pub trait DetElement: Copy + Send + Sync + Sized {
+    fn det<const N: usize>(m: &Matrix<N, N, Self>) -> Result<Self, error::OperationError>;
+}
+
+fn permutation_det<const N: usize, T>(m: &Matrix<N, N, T>) -> Result<T, error::OperationError>
+where
+    T: PermutationDet,
+{
+    let mut sum = T::default();
+    let permutation = util::permutation(N)?;
+    for perm in permutation {
+        sum = sum + util::determinant_in_one_permutation(&m.data, &perm)?;
+    }
+    Ok(sum)
+}
+
+// 相对主元容差下奇异的矩阵行列式记为 0
+fn lu_det<const N: usize>(
+    lu: Result<LUDecomposition<N>, error::OperationError>,
+) -> Result<f64, error::OperationError> {
+    match lu {
+        Ok(lu) => Ok(lu.det()),
+        Err(error::OperationError::Singular { .. }) => Ok(0.0),
+        Err(e) => Err(e),
+    }
+}
+
+impl DetElement for f64 {
+    fn det<const N: usize>(m: &Matrix<N, N, f64>) -> Result<f64, error::OperationError> {
+        lu_det(m.lu())
+    }
+}
+
+impl DetElement for f32 {
+    fn det<const N: usize>(m: &Matrix<N, N, f32>) -> Result<f32, error::OperationError> {
+        lu_det(m.lu()).map(|det| det as f32)
+    }
+}
+
+// 按置换展开求精确行列式的元素类型（整数、有理数等），自定义类型实现该标记即可使用 det()
+pub trait PermutationDet:
+    Copy + Display + Default + Add<Output = Self> + Send + Sync + From<i8> + Mul<Output = Self>
+{
+}
+
+impl<T: PermutationDet> DetElement for T {
+    fn det<const N: usize>(m: &Matrix<N, N, T>) -> Result<T, error::OperationError> {
+        permutation_det(m)
+    }
+}
+
+impl PermutationDet for i8 {}
+impl PermutationDet for i16 {}
+impl PermutationDet for i32 {}
+impl PermutationDet for i64 {}
+impl PermutationDet for i128 {}
+impl PermutationDet for isize {}
+
 impl<const N: usize, T> Matrix<N, N, T>
 where
-    T: Copy + Display + Default + Add<Output = T> + Send + Sync + From<i8> + Mul<Output = T>,
+    T: DetElement,
 {
     pub fn det(&self) -> Result<T, error::OperationError> {
-        let mut sum = T::default();
-        let permutation = util::permutation(N)?;
-        for perm in permutation {
-            let res = util::determinant_in_one_permutation(&self.data, &perm);
-            match res {
-                Ok(x) => sum = sum + x,
-                Err(e) => return Err(e),
-            }
-        }
-        Ok(sum)
+        T::det(self)
     }
-
 }
 
 impl<const ROWS: usize, const COLS: usize, T> Debug for Matrix<ROWS, COLS, T>
@@ -127,7 +164,7 @@ where
     T: Display + Copy + Send + Sync,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.display())
+        self.fmt_with_flags(f)
     }
 }
 
@@ -135,8 +172,9 @@ impl<const ROWS: usize, const COLS: usize, T> Display for Matrix<ROWS, COLS, T>
 where
     T: Copy + Display + Send + Sync,
 {
+    // 支持 {:.3}、{:8}、{:>} 等精度、宽度与对齐参数
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.display())
+        self.fmt_with_flags(f)
     }
 }
 
@@ -242,4 +280,43 @@ mod test {
         let m = Matrix::<3, 3, i32>::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
         assert_eq!(m.det().unwrap(), 0);
     }
+
+    // 下游的元素类型实现 PermutationDet 标记后同样可以求行列式
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    struct Mod7(u8);
+
+    impl From<i8> for Mod7 {
+        fn from(v: i8) -> Self {
+            Mod7(v.rem_euclid(7) as u8)
+        }
+    }
+
+    impl Add for Mod7 {
+        type Output = Mod7;
+        fn add(self, rhs: Mod7) -> Mod7 {
+            Mod7((self.0 + rhs.0) % 7)
+        }
+    }
+
+    impl Mul for Mod7 {
+        type Output = Mod7;
+        fn mul(self, rhs: Mod7) -> Mod7 {
+            Mod7(self.0 * rhs.0 % 7)
+        }
+    }
+
+    impl Display for Mod7 {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl PermutationDet for Mod7 {}
+
+    #[test]
+    fn test_det_custom_element() {
+        // det = 1·4 - 2·3 = -2 ≡ 5 (mod 7)
+        let m = Matrix::<2, 2, Mod7>::new([[Mod7(1), Mod7(2)], [Mod7(3), Mod7(4)]]);
+        assert_eq!(m.det().unwrap(), Mod7(5));
+    }
 }
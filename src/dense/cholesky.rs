@@ -0,0 +1,173 @@
+use crate::constant::get_flt64_zero;
+use crate::dense::error::OperationError;
+use crate::dense::Matrix;
+
+// Cholesky 分解结果：A = L·Lᵀ
+#[derive(Clone, Copy, Debug)]
+pub struct CholeskyDecomposition<const N: usize> {
+    // 对角元为正的下三角矩阵
+    pub l: Matrix<N, N, f64>,
+}
+
+impl<const N: usize> CholeskyDecomposition<N> {
+    // 求解 A·x = b，先前代 L·y = b，再回代 Lᵀ·x = y
+    pub fn solve_vector(&self, b: &[f64; N]) -> [f64; N] {
+        let l = &self.l.data;
+        let mut x = *b;
+        for i in 0..N {
+            for j in 0..i {
+                x[i] -= l[i][j] * x[j];
+            }
+            x[i] /= l[i][i];
+        }
+        for i in (0..N).rev() {
+            for j in (i + 1)..N {
+                x[i] -= l[j][i] * x[j];
+            }
+            x[i] /= l[i][i];
+        }
+        x
+    }
+
+    // 求解 A·X = B，B 的每一列是一个右端项
+    pub fn solve<const K: usize>(&self, b: &Matrix<N, K, f64>) -> Matrix<N, K, f64> {
+        let mut data = [[0.0; K]; N];
+        for col in 0..K {
+            let mut rhs = [0.0; N];
+            for row in 0..N {
+                rhs[row] = b.data[row][col];
+            }
+            let x = self.solve_vector(&rhs);
+            for row in 0..N {
+                data[row][col] = x[row];
+            }
+        }
+        Matrix::new(data)
+    }
+}
+
+impl<const N: usize> Matrix<N, N, f64> {
+    // Cholesky 分解，矩阵不对称或非正定时返回错误。
+    // 容差都相对于对应的原始元素，量级相差很大的协方差矩阵（例如 diag(1e6, 1e-10)）也能分解
+    pub fn cholesky(&self) -> Result<CholeskyDecomposition<N>, OperationError> {
+        let a = &self.data;
+        let mut l = [[0.0; N]; N];
+        for i in 0..N {
+            for j in (i + 1)..N {
+                // 对称正定矩阵满足 |aᵢⱼ| ≤ sqrt(aᵢᵢ·aⱼⱼ)
+                let scale = (a[i][i].abs() * a[j][j].abs())
+                    .sqrt()
+                    .max(a[i][j].abs())
+                    .max(a[j][i].abs());
+                if (a[i][j] - a[j][i]).abs() > get_flt64_zero() * scale {
+                    return Err(OperationError::NotSymmetric);
+                }
+            }
+        }
+        for j in 0..N {
+            let mut diag = a[j][j];
+            for k in 0..j {
+                diag -= l[j][k] * l[j][k];
+            }
+            if diag <= get_flt64_zero() * a[j][j] || diag <= 0.0 || diag.is_nan() {
+                return Err(OperationError::NotPositiveDefinite);
+            }
+            let ljj = diag.sqrt();
+            l[j][j] = ljj;
+            for i in (j + 1)..N {
+                let mut sum = a[i][j];
+                for k in 0..j {
+                    sum -= l[i][k] * l[j][k];
+                }
+                l[i][j] = sum / ljj;
+            }
+        }
+        Ok(CholeskyDecomposition { l: Matrix::new(l) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_cholesky() {
+        let m = Matrix::<3, 3, f64>::new([[4.0, 12.0, -16.0], [12.0, 37.0, -43.0], [-16.0, -43.0, 98.0]]);
+        let chol = m.cholesky().unwrap();
+        let expected = [[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]];
+        for row in 0..3 {
+            for col in 0..3 {
+                assert_relative_eq!(chol.l.data[row][col], expected[row][col], epsilon = 1e-12);
+            }
+        }
+        let rebuilt = chol.l.product(&chol.l.T()).unwrap();
+        for row in 0..3 {
+            for col in 0..3 {
+                assert_relative_eq!(rebuilt.data[row][col], m.data[row][col], epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cholesky_not_positive_definite() {
+        let m = Matrix::<2, 2, f64>::new([[1.0, 2.0], [2.0, 1.0]]);
+        let err = m.cholesky().unwrap_err();
+        assert_eq!(err, OperationError::NotPositiveDefinite);
+
+        let m = Matrix::<2, 2, f64>::new([[2.0, 1.0], [0.0, 2.0]]);
+        let err = m.cholesky().unwrap_err();
+        assert_eq!(err, OperationError::NotSymmetric);
+    }
+
+    #[test]
+    fn test_cholesky_mixed_scale() {
+        let m = Matrix::<2, 2, f64>::new([[1e6, 0.0], [0.0, 1e-10]]);
+        let chol = m.cholesky().unwrap();
+        assert_relative_eq!(chol.l.data[0][0], 1e3, max_relative = 1e-12);
+        assert_relative_eq!(chol.l.data[1][1], 1e-5, max_relative = 1e-12);
+
+        // 位置用 km²、姿态用 rad² 的协方差，相关项很小
+        let m = Matrix::<3, 3, f64>::new([
+            [4e6, 2e-3, 0.0],
+            [2e-3, 1e-8, 1e-13],
+            [0.0, 1e-13, 1e-12],
+        ]);
+        let chol = m.cholesky().unwrap();
+        let rebuilt = chol.l.product(&chol.l.T()).unwrap();
+        for row in 0..3 {
+            for col in 0..3 {
+                assert_relative_eq!(
+                    rebuilt.data[row][col],
+                    m.data[row][col],
+                    epsilon = 1e-20,
+                    max_relative = 1e-10
+                );
+            }
+        }
+
+        // 量级小的块不对称仍然报错
+        let m = Matrix::<2, 2, f64>::new([[1e-10, 2e-11], [1e-11, 1e-10]]);
+        assert_eq!(m.cholesky().unwrap_err(), OperationError::NotSymmetric);
+        let m = Matrix::<2, 2, f64>::new([[1e-10, 0.0], [0.0, -1e-20]]);
+        assert_eq!(m.cholesky().unwrap_err(), OperationError::NotPositiveDefinite);
+    }
+
+    #[test]
+    fn test_cholesky_solve() {
+        let m = Matrix::<3, 3, f64>::new([[4.0, 12.0, -16.0], [12.0, 37.0, -43.0], [-16.0, -43.0, 98.0]]);
+        let chol = m.cholesky().unwrap();
+        let x = chol.solve_vector(&[0.0, 6.0, 39.0]);
+        assert_relative_eq!(x[0], 1.0, epsilon = 1e-10);
+        assert_relative_eq!(x[1], 1.0, epsilon = 1e-10);
+        assert_relative_eq!(x[2], 1.0, epsilon = 1e-10);
+
+        let b = Matrix::<3, 2, f64>::new([[0.0, 4.0], [6.0, 12.0], [39.0, -16.0]]);
+        let x = chol.solve(&b);
+        for row in 0..3 {
+            assert_relative_eq!(x.data[row][0], 1.0, epsilon = 1e-10);
+            let expected = if row == 0 { 1.0 } else { 0.0 };
+            assert_relative_eq!(x.data[row][1], expected, epsilon = 1e-10);
+        }
+    }
+}
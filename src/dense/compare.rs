@@ -0,0 +1,91 @@
+use crate::dense::Matrix;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+// 只比较元素，digits 只影响输出格式
+impl<const ROWS: usize, const COLS: usize, T> PartialEq for Matrix<ROWS, COLS, T>
+where
+    T: Copy + Send + Sync + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, T> AbsDiffEq for Matrix<ROWS, COLS, T>
+where
+    T: Copy + Send + Sync + AbsDiffEq,
+    T::Epsilon: Copy,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, T> RelativeEq for Matrix<ROWS, COLS, T>
+where
+    T: Copy + Send + Sync + RelativeEq,
+    T::Epsilon: Copy,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, T> UlpsEq for Matrix<ROWS, COLS, T>
+where
+    T: Copy + Send + Sync + UlpsEq,
+    T::Epsilon: Copy,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq};
+
+    #[test]
+    fn test_partial_eq() {
+        let a = Matrix::<2, 2, i32>::new([[1, 2], [3, 4]]);
+        let mut b = a;
+        b.set_digits(3);
+        assert_eq!(a, b);
+        b.data[1][1] = 5;
+        assert_ne!(a, b);
+        assert_ne!(Matrix::<1, 1, f64>::new([[f64::NAN]]), Matrix::<1, 1, f64>::new([[f64::NAN]]));
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let a = Matrix::<2, 2, f64>::new([[0.1 + 0.2, 1.0], [1e10, -2.0]]);
+        let b = Matrix::<2, 2, f64>::new([[0.3, 1.0], [1e10 + 1e-6, -2.0]]);
+        assert_ne!(a, b);
+        assert_relative_eq!(a, b);
+        assert_ulps_eq!(a.scale(1.0), Matrix::new([[0.3, 1.0], [1e10, -2.0]]));
+        assert_abs_diff_eq!(a, b, epsilon = 1e-5);
+        assert!(a.abs_diff_ne(&b, 1e-7));
+        assert_relative_ne!(a, Matrix::new([[0.3, 1.0], [1e10, -2.1]]));
+    }
+}
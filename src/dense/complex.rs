@@ -0,0 +1,95 @@
+use crate::dense::Matrix;
+use num_complex::Complex;
+
+impl<const ROWS: usize, const COLS: usize> Matrix<ROWS, COLS, f64> {
+    // 虚部为 0 的复数矩阵
+    pub fn to_complex(&self) -> Matrix<ROWS, COLS, Complex<f64>> {
+        self.map(|re| Complex::new(re, 0.0))
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> Matrix<ROWS, COLS, Complex<f64>> {
+    // 由实部与虚部矩阵组成复数矩阵
+    pub fn from_parts(re: &Matrix<ROWS, COLS, f64>, im: &Matrix<ROWS, COLS, f64>) -> Self {
+        re.zip_map(im, Complex::new)
+    }
+
+    pub fn re(&self) -> Matrix<ROWS, COLS, f64> {
+        self.map(|c| c.re)
+    }
+
+    pub fn im(&self) -> Matrix<ROWS, COLS, f64> {
+        self.map(|c| c.im)
+    }
+
+    // 逐元素取模，例如频率响应的幅值
+    pub fn modulus(&self) -> Matrix<ROWS, COLS, f64> {
+        self.map(|c| c.norm())
+    }
+
+    // 逐元素辐角（弧度），范围 (-π, π]
+    pub fn arg(&self) -> Matrix<ROWS, COLS, f64> {
+        self.map(|c| c.arg())
+    }
+
+    // 逐元素共轭
+    pub fn conj(&self) -> Self {
+        self.map(|c| c.conj())
+    }
+
+    // 共轭转置 Aᴴ
+    #[allow(non_snake_case)]
+    pub fn H(&self) -> Matrix<COLS, ROWS, Complex<f64>> {
+        self.T().conj()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn test_arithmetic_and_product() {
+        let a = Matrix::<2, 2, Complex<f64>>::new([[c(1.0, 1.0), c(0.0, 2.0)], [c(3.0, 0.0), c(1.0, -1.0)]]);
+        let b = Matrix::<2, 1, Complex<f64>>::new([[c(0.0, 1.0)], [c(2.0, 0.0)]]);
+        assert_eq!(a.product(&b).unwrap(), Matrix::new([[c(-1.0, 5.0)], [c(2.0, 1.0)]]));
+        assert_eq!((a + a).re(), a.re().scale(2.0));
+        assert_eq!(a - a, Matrix::<2, 2, f64>::zeros().to_complex());
+        assert_eq!(a.scale(c(0.0, 1.0)).get(0, 0), Some(c(-1.0, 1.0)));
+    }
+
+    #[test]
+    fn test_conjugate_transpose() {
+        let a = Matrix::<2, 3, Complex<f64>>::from_parts(
+            &Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]),
+            &Matrix::new([[1.0, -1.0, 0.0], [0.0, 2.0, -2.0]]),
+        );
+        let h = a.H();
+        assert_eq!(h.re(), a.re().T());
+        assert_eq!(h.im(), -a.im().T());
+        assert_eq!(h.H(), a);
+        // A·Aᴴ 是 Hermite 矩阵，对角元为实数
+        let g = a.product(&h).unwrap();
+        assert_eq!(g, g.H());
+        assert_eq!(g.im().get(1, 1), Some(0.0));
+    }
+
+    #[test]
+    fn test_modulus_and_arg() {
+        let m = Matrix::<1, 2, f64>::new([[3.0, -1.0]]).to_complex() + c(0.0, 4.0);
+        assert_eq!(m.modulus(), Matrix::new([[5.0, 17f64.sqrt()]]));
+        assert_relative_eq!(m.arg(), Matrix::new([[(4.0f64).atan2(3.0), (4.0f64).atan2(-1.0)]]));
+    }
+
+    #[test]
+    fn test_display() {
+        let m = Matrix::<1, 2, Complex<f64>>::new([[c(1.0, -2.0), c(0.5, 0.25)]]);
+        assert_eq!(m.formatted().compact().to_string(), "[[1-2i, 0.5+0.25i]]");
+        assert_eq!(format!("{:.1}", m), "[1.0-2.0i  0.5+0.2i  ]\n");
+    }
+}
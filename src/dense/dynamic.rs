@@ -0,0 +1,524 @@
+use crate::dense::error::OperationError;
+use crate::dense::{format, Matrix};
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub};
+
+// 运行时确定尺寸的矩阵，数据按行优先存放在堆上
+#[derive(Clone, PartialEq)]
+pub struct DMatrix<T>
+where
+    T: Copy,
+{
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T> DMatrix<T>
+where
+    T: Copy,
+{
+    // data 按行优先排列，长度必须等于 rows * cols
+    pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Result<Self, OperationError> {
+        if data.len() != rows * cols {
+            return Err(OperationError::LengthMismatch {
+                expected: rows * cols,
+                found: data.len(),
+            });
+        }
+        Ok(DMatrix { rows, cols, data })
+    }
+
+    // 由二维 Vec 构造，每行长度必须一致
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, OperationError> {
+        let height = rows.len();
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        let mut data = Vec::with_capacity(height * width);
+        for row in rows {
+            if row.len() != width {
+                return Err(OperationError::LengthMismatch {
+                    expected: width,
+                    found: row.len(),
+                });
+            }
+            data.extend(row);
+        }
+        Ok(DMatrix {
+            rows: height,
+            cols: width,
+            data,
+        })
+    }
+
+    pub fn new_with_default(rows: usize, cols: usize, v: T) -> Self {
+        DMatrix {
+            rows,
+            cols,
+            data: vec![v; rows * cols],
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows
+    }
+
+    pub fn width(&self) -> usize {
+        self.cols
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    // 按 (行, 列) 读取
+    pub fn get(&self, row: usize, col: usize) -> Option<T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        Some(self.data[row * self.cols + col])
+    }
+
+    // 按 (行, 列) 写入
+    pub fn set(&mut self, row: usize, col: usize, value: T) -> Result<(), OperationError> {
+        if row >= self.rows || col >= self.cols {
+            return Err(OperationError::IndexOutOfBounds {
+                index: (row, col),
+                shape: self.size(),
+            });
+        }
+        self.data[row * self.cols + col] = value;
+        Ok(())
+    }
+
+    // 按行优先顺序的底层数据
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.rows).map(move |row| &self.data[row * self.cols..(row + 1) * self.cols])
+    }
+
+    pub fn map<U, F>(&self, func: F) -> DMatrix<U>
+    where
+        U: Copy,
+        F: FnMut(T) -> U,
+    {
+        DMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().copied().map(func).collect(),
+        }
+    }
+
+    pub fn zip_map<U, V, F>(&self, other: &DMatrix<U>, mut func: F) -> Result<DMatrix<V>, OperationError>
+    where
+        U: Copy,
+        V: Copy,
+        F: FnMut(T, U) -> V,
+    {
+        if self.size() != other.size() {
+            return Err(OperationError::DimensionMismatch {
+                expected: self.size(),
+                found: other.size(),
+            });
+        }
+        Ok(DMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(&a, &b)| func(a, b))
+                .collect(),
+        })
+    }
+
+    #[allow(non_snake_case)]
+    pub fn T(&self) -> DMatrix<T> {
+        let mut data = Vec::with_capacity(self.data.len());
+        for col in 0..self.cols {
+            for row in 0..self.rows {
+                data.push(self.data[row * self.cols + col]);
+            }
+        }
+        DMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            data,
+        }
+    }
+
+    pub fn reshape(&self, rows: usize, cols: usize) -> Result<DMatrix<T>, OperationError> {
+        if self.rows * self.cols != rows * cols {
+            return Err(OperationError::LengthMismatch {
+                expected: self.rows * self.cols,
+                found: rows * cols,
+            });
+        }
+        Ok(DMatrix {
+            rows,
+            cols,
+            data: self.data.clone(),
+        })
+    }
+
+    // 转换为定长矩阵，尺寸必须一致
+    pub fn to_matrix<const ROWS: usize, const COLS: usize>(
+        &self,
+    ) -> Result<Matrix<ROWS, COLS, T>, OperationError>
+    where
+        T: Send + Sync,
+    {
+        if self.rows != ROWS || self.cols != COLS {
+            return Err(OperationError::DimensionMismatch {
+                expected: (ROWS, COLS),
+                found: self.size(),
+            });
+        }
+        let data = array_init::array_init(|row| {
+            array_init::array_init(|col| self.data[row * COLS + col])
+        });
+        Ok(Matrix { data, digits: 0 })
+    }
+}
+
+impl<T> DMatrix<T>
+where
+    T: Copy + Default,
+{
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self::new_with_default(rows, cols, T::default())
+    }
+}
+
+impl<T> DMatrix<T>
+where
+    T: Copy + Default + From<i8>,
+{
+    pub fn ones(rows: usize, cols: usize) -> Self {
+        Self::new_with_default(rows, cols, T::from(1))
+    }
+
+    // 生成 n 阶单位方阵
+    pub fn unit(n: usize) -> Self {
+        let mut m = Self::zeros(n, n);
+        for i in 0..n {
+            m.data[i * n + i] = T::from(1);
+        }
+        m
+    }
+}
+
+impl<T> DMatrix<T>
+where
+    T: Copy + Default + Add<Output = T> + Mul<Output = T>,
+{
+    pub fn product(&self, target: &DMatrix<T>) -> Result<DMatrix<T>, OperationError> {
+        if self.cols != target.rows {
+            return Err(OperationError::DimensionMismatch {
+                expected: (self.cols, target.cols),
+                found: target.size(),
+            });
+        }
+        let mut data = vec![T::default(); self.rows * target.cols];
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let a = self.data[i * self.cols + k];
+                for j in 0..target.cols {
+                    let cell = &mut data[i * target.cols + j];
+                    *cell = *cell + a * target.data[k * target.cols + j];
+                }
+            }
+        }
+        Ok(DMatrix {
+            rows: self.rows,
+            cols: target.cols,
+            data,
+        })
+    }
+
+    pub fn scale(&self, scalar: T) -> DMatrix<T> {
+        self.map(|a| a * scalar)
+    }
+
+    pub fn sum(&self) -> T {
+        self.data.iter().fold(T::default(), |sum, &value| sum + value)
+    }
+}
+
+impl<T> Index<(usize, usize)> for DMatrix<T>
+where
+    T: Copy,
+{
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(row < self.rows && col < self.cols, "index out of bounds");
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for DMatrix<T>
+where
+    T: Copy,
+{
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        assert!(row < self.rows && col < self.cols, "index out of bounds");
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+// 尺寸只能在运行时检查，因此逐元素运算返回 Result
+impl<T> Add for DMatrix<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    type Output = Result<DMatrix<T>, OperationError>;
+
+    fn add(self, other: Self) -> Self::Output {
+        self.zip_map(&other, |a, b| a + b)
+    }
+}
+
+impl<T> Sub for DMatrix<T>
+where
+    T: Copy + Sub<Output = T>,
+{
+    type Output = Result<DMatrix<T>, OperationError>;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self.zip_map(&other, |a, b| a - b)
+    }
+}
+
+impl<T> Mul for DMatrix<T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    type Output = Result<DMatrix<T>, OperationError>;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.zip_map(&other, |a, b| a * b)
+    }
+}
+
+impl<T> Neg for DMatrix<T>
+where
+    T: Copy + Neg<Output = T>,
+{
+    type Output = DMatrix<T>;
+
+    fn neg(self) -> Self::Output {
+        self.map(|a| -a)
+    }
+}
+
+impl<T> Add<T> for DMatrix<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    type Output = DMatrix<T>;
+
+    fn add(self, rhs: T) -> Self::Output {
+        self.map(|a| a + rhs)
+    }
+}
+
+impl<T> Sub<T> for DMatrix<T>
+where
+    T: Copy + Sub<Output = T>,
+{
+    type Output = DMatrix<T>;
+
+    fn sub(self, rhs: T) -> Self::Output {
+        self.map(|a| a - rhs)
+    }
+}
+
+impl<T> Mul<T> for DMatrix<T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    type Output = DMatrix<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|a| a * rhs)
+    }
+}
+
+impl<T> Div<T> for DMatrix<T>
+where
+    T: Copy + Div<Output = T>,
+{
+    type Output = DMatrix<T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        self.map(|a| a / rhs)
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, T> From<Matrix<ROWS, COLS, T>> for DMatrix<T>
+where
+    T: Copy + Send + Sync,
+{
+    fn from(m: Matrix<ROWS, COLS, T>) -> Self {
+        DMatrix {
+            rows: ROWS,
+            cols: COLS,
+            data: m.data.as_flattened().to_vec(),
+        }
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, T> TryFrom<DMatrix<T>> for Matrix<ROWS, COLS, T>
+where
+    T: Copy + Send + Sync,
+{
+    type Error = OperationError;
+
+    fn try_from(m: DMatrix<T>) -> Result<Self, Self::Error> {
+        m.to_matrix()
+    }
+}
+
+impl<T> Display for DMatrix<T>
+where
+    T: Copy + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        format::fmt_slice_with_flags(&self.data, self.rows, self.cols, f)
+    }
+}
+
+impl<T> Debug for DMatrix<T>
+where
+    T: Copy + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        format::fmt_slice_with_flags(&self.data, self.rows, self.cols, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let m = DMatrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(m.size(), (2, 3));
+        assert_eq!(m.get(1, 0), Some(4));
+        assert_eq!(m.get(2, 0), None);
+        assert!(DMatrix::new(2, 2, vec![1, 2, 3]).is_err());
+
+        let m = DMatrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        assert_eq!(m[(1, 1)], 4);
+        assert!(DMatrix::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+    }
+
+    #[test]
+    fn test_set_and_index() {
+        let mut m = DMatrix::<i32>::zeros(2, 2);
+        m.set(0, 1, 5).unwrap();
+        m[(1, 0)] = 7;
+        assert_eq!(m.as_slice(), &[0, 5, 7, 0]);
+        assert!(m.set(2, 0, 1).is_err());
+    }
+
+    #[test]
+    fn test_unit_and_ones() {
+        let m = DMatrix::<f64>::unit(3);
+        assert_eq!(m.as_slice(), &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(DMatrix::<i32>::ones(1, 2).as_slice(), &[1, 1]);
+    }
+
+    #[test]
+    fn test_elementwise() {
+        let a = DMatrix::new(2, 2, vec![1, 2, 3, 4]).unwrap();
+        let b = DMatrix::new(2, 2, vec![5, 6, 7, 8]).unwrap();
+        assert_eq!((a.clone() + b.clone()).unwrap().as_slice(), &[6, 8, 10, 12]);
+        assert_eq!((b.clone() - a.clone()).unwrap().as_slice(), &[4, 4, 4, 4]);
+        assert_eq!((a.clone() * b.clone()).unwrap().as_slice(), &[5, 12, 21, 32]);
+        assert_eq!((-a.clone()).as_slice(), &[-1, -2, -3, -4]);
+        assert_eq!((a.clone() + 1).as_slice(), &[2, 3, 4, 5]);
+        assert_eq!((a.clone() - 1).as_slice(), &[0, 1, 2, 3]);
+        assert_eq!((a.clone() * 2).as_slice(), &[2, 4, 6, 8]);
+        assert_eq!((b / 2).as_slice(), &[2, 3, 3, 4]);
+
+        let c = DMatrix::new(1, 4, vec![1, 2, 3, 4]).unwrap();
+        let err = (a + c).unwrap_err();
+        assert_eq!(
+            err,
+            OperationError::DimensionMismatch {
+                expected: (2, 2),
+                found: (1, 4)
+            }
+        );
+    }
+
+    #[test]
+    fn test_product() {
+        let a = DMatrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let b = DMatrix::new(3, 4, vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]).unwrap();
+        let c = a.product(&b).unwrap();
+        assert_eq!(c.size(), (2, 4));
+        assert_eq!(c.as_slice(), &[38, 44, 50, 56, 83, 98, 113, 128]);
+        assert!(b.product(&a).is_err());
+    }
+
+    #[test]
+    fn test_transpose_and_reshape() {
+        let a = DMatrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let t = a.T();
+        assert_eq!(t.size(), (3, 2));
+        assert_eq!(t.as_slice(), &[1, 4, 2, 5, 3, 6]);
+        let r = a.reshape(3, 2).unwrap();
+        assert_eq!(r.size(), (3, 2));
+        assert_eq!(r.as_slice(), &[1, 2, 3, 4, 5, 6]);
+        assert!(a.reshape(4, 2).is_err());
+    }
+
+    #[test]
+    fn test_conversion() {
+        let m = Matrix::<2, 2, i32>::new([[1, 2], [3, 4]]);
+        let d = DMatrix::from(m);
+        assert_eq!(d.size(), (2, 2));
+        let back: Matrix<2, 2, i32> = d.clone().try_into().unwrap();
+        assert_eq!(back.data, [[1, 2], [3, 4]]);
+        let wrong: Result<Matrix<1, 4, i32>, _> = d.try_into();
+        assert!(wrong.is_err());
+    }
+
+    #[test]
+    fn test_display_matches_matrix() {
+        let m = Matrix::<3, 3, i32>::new([[1, 2, 3], [4, 5, 6], [7, 8, 90]]);
+        let d = DMatrix::from(m);
+        assert_eq!(format!("{}", d), format!("{}", m));
+        let single = DMatrix::new(1, 3, vec![1, 2, 3]).unwrap();
+        assert_eq!(format!("{}", single), "[1  2  3  ]\n");
+        assert_eq!(format!("{}", DMatrix::<i32>::zeros(0, 0)), "┌┐\n└┘\n");
+
+        // 精度、宽度与对齐参数与 Matrix 一致
+        let m = Matrix::<2, 2, f64>::new([[1.0, 2.34567], [-3.5, 4.0]]);
+        let d = DMatrix::from(m);
+        assert_eq!(format!("{:.2}", d), "┌1.00   2.35   ┐\n└-3.50  4.00   ┘\n");
+        assert_eq!(format!("{:.2}", d), format!("{:.2}", m));
+        assert_eq!(format!("{:>8.1}", d), format!("{:>8.1}", m));
+        assert_eq!(format!("{:?}", d), format!("{:?}", m));
+    }
+
+    #[test]
+    fn test_rows() {
+        let a = DMatrix::new(2, 2, vec![1, 2, 3, 4]).unwrap();
+        let rows: Vec<&[i32]> = a.rows().collect();
+        assert_eq!(rows, vec![&[1, 2][..], &[3, 4][..]]);
+        assert_eq!(a.sum(), 10);
+        assert_eq!(a.scale(3).as_slice(), &[3, 6, 9, 12]);
+    }
+}
@@ -0,0 +1,506 @@
+use crate::constant::get_flt64_zero;
+use crate::dense::error::OperationError;
+use crate::dense::Matrix;
+use num_complex::Complex;
+
+const MAX_JACOBI_SWEEPS: usize = 100;
+// 每个特征值允许的 QR 迭代次数，第 10、20 次使用特殊位移
+const MAX_QR_ITERATIONS: usize = 30;
+
+// 对称矩阵的特征分解：A = V·diag(values)·Vᵀ
+#[derive(Clone, Copy, Debug)]
+pub struct SymmetricEigen<const N: usize> {
+    // 特征值，按从小到大排序
+    pub values: [f64; N],
+    // 正交矩阵，第 i 列是 values[i] 对应的单位特征向量
+    pub vectors: Matrix<N, N, f64>,
+}
+
+impl<const N: usize> SymmetricEigen<N> {
+    // 取第 i 个特征向量
+    pub fn vector(&self, i: usize) -> Option<[f64; N]> {
+        if i >= N {
+            return None;
+        }
+        let mut v = [0.0; N];
+        for row in 0..N {
+            v[row] = self.vectors.data[row][i];
+        }
+        Some(v)
+    }
+
+    // 最大特征值及其特征向量
+    pub fn max(&self) -> Option<(f64, [f64; N])> {
+        if N == 0 {
+            return None;
+        }
+        Some((self.values[N - 1], self.vector(N - 1)?))
+    }
+}
+
+impl<const N: usize> Matrix<N, N, f64> {
+    // 循环 Jacobi 法求对称矩阵的特征值与特征向量
+    // 非对角元的平方和不大于 (get_flt64_zero()·‖A‖F)² 时视为收敛
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<N>, OperationError> {
+        let mut a = self.data;
+        let mut scale = 0.0;
+        for row in 0..N {
+            for col in 0..N {
+                scale += a[row][col] * a[row][col];
+            }
+        }
+        let scale = scale.sqrt();
+        let tolerance = get_flt64_zero() * scale.max(1.0);
+        for row in 0..N {
+            for col in (row + 1)..N {
+                if (a[row][col] - a[col][row]).abs() > tolerance {
+                    return Err(OperationError::NotSymmetric);
+                }
+            }
+        }
+
+        let mut v = [[0.0; N]; N];
+        for i in 0..N {
+            v[i][i] = 1.0;
+        }
+        let mut converged = false;
+        for _ in 0..MAX_JACOBI_SWEEPS {
+            let mut off = 0.0;
+            for row in 0..N {
+                for col in (row + 1)..N {
+                    off += a[row][col] * a[row][col];
+                }
+            }
+            if off.sqrt() <= tolerance {
+                converged = true;
+                break;
+            }
+            for p in 0..N {
+                for q in (p + 1)..N {
+                    if a[p][q] == 0.0 {
+                        continue;
+                    }
+                    let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+                    for k in 0..N {
+                        let akp = a[k][p];
+                        let akq = a[k][q];
+                        a[k][p] = c * akp - s * akq;
+                        a[k][q] = s * akp + c * akq;
+                    }
+                    for k in 0..N {
+                        let apk = a[p][k];
+                        let aqk = a[q][k];
+                        a[p][k] = c * apk - s * aqk;
+                        a[q][k] = s * apk + c * aqk;
+                    }
+                    for k in 0..N {
+                        let vkp = v[k][p];
+                        let vkq = v[k][q];
+                        v[k][p] = c * vkp - s * vkq;
+                        v[k][q] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+        if !converged {
+            return Err(OperationError::NotConverged {
+                algorithm: "jacobi eigenvalue iteration",
+                iterations: MAX_JACOBI_SWEEPS,
+            });
+        }
+
+        let mut order = [0usize; N];
+        for (i, o) in order.iter_mut().enumerate() {
+            *o = i;
+        }
+        order.sort_by(|&i, &j| a[i][i].total_cmp(&a[j][j]));
+        let mut values = [0.0; N];
+        let mut vectors = [[0.0; N]; N];
+        for (col, &src) in order.iter().enumerate() {
+            values[col] = a[src][src];
+            for row in 0..N {
+                vectors[row][col] = v[row][src];
+            }
+        }
+        Ok(SymmetricEigen {
+            values,
+            vectors: Matrix::new(vectors),
+        })
+    }
+
+    // 一般（非对称）实矩阵的特征值：平衡、化为上 Hessenberg 形后做 Francis 双位移 QR 迭代
+    // 共轭复特征值成对出现，结果按实部、虚部从小到大排序
+    pub fn eigenvalues(&self) -> Result<[Complex<f64>; N], OperationError> {
+        // NaN 与无穷会让 QR 迭代得到无意义的结果
+        if let Some(&value) = self.data.iter().flatten().find(|v| !v.is_finite()) {
+            return Err(OperationError::InvalidValue {
+                name: "matrix element",
+                value,
+            });
+        }
+        let mut a = self.data;
+        balance(&mut a);
+        hessenberg(&mut a);
+        let mut values = hessenberg_qr(&mut a)?;
+        values.sort_by(|x, y| x.re.total_cmp(&y.re).then(x.im.total_cmp(&y.im)));
+        Ok(values)
+    }
+}
+
+// 用 2 的幂做相似变换，使各行与对应列的范数接近，减小舍入误差
+fn balance<const N: usize>(a: &mut [[f64; N]; N]) {
+    const RADIX: f64 = 2.0;
+    let mut done = false;
+    while !done {
+        done = true;
+        for i in 0..N {
+            // 去掉对角元的第 i 行与第 i 列的 1-范数
+            let r: f64 = a[i].iter().map(|v| v.abs()).sum::<f64>() - a[i][i].abs();
+            let mut c: f64 = a.iter().map(|row| row[i].abs()).sum::<f64>() - a[i][i].abs();
+            if c == 0.0 || r == 0.0 {
+                continue;
+            }
+            let s = c + r;
+            let mut f = 1.0;
+            while c < r / RADIX {
+                f *= RADIX;
+                c *= RADIX * RADIX;
+            }
+            while c > r * RADIX {
+                f /= RADIX;
+                c /= RADIX * RADIX;
+            }
+            if (c + r) / f < 0.95 * s {
+                done = false;
+                for value in a[i].iter_mut() {
+                    *value /= f;
+                }
+                for row in a.iter_mut() {
+                    row[i] *= f;
+                }
+            }
+        }
+    }
+}
+
+// 带选主元的 Gauss 消去化为上 Hessenberg 形（相似变换，不保留变换矩阵）
+fn hessenberg<const N: usize>(a: &mut [[f64; N]; N]) {
+    for m in 1..N.saturating_sub(1) {
+        let mut pivot = m;
+        for j in m + 1..N {
+            if a[j][m - 1].abs() > a[pivot][m - 1].abs() {
+                pivot = j;
+            }
+        }
+        let x = a[pivot][m - 1];
+        if pivot != m {
+            a.swap(pivot, m);
+            for row in a.iter_mut() {
+                row.swap(pivot, m);
+            }
+        }
+        if x == 0.0 {
+            continue;
+        }
+        for i in m + 1..N {
+            let y = a[i][m - 1] / x;
+            if y == 0.0 {
+                continue;
+            }
+            a[i][m - 1] = 0.0;
+            let pivot_row = a[m];
+            for (value, &p) in a[i][m..].iter_mut().zip(&pivot_row[m..]) {
+                *value -= y * p;
+            }
+            for row in a.iter_mut() {
+                row[m] += y * row[i];
+            }
+        }
+    }
+}
+
+// 上 Hessenberg 矩阵的隐式双位移 QR 迭代，逐个（或成对）收缩出特征值
+fn hessenberg_qr<const N: usize>(a: &mut [[f64; N]; N]) -> Result<[Complex<f64>; N], OperationError> {
+    let mut values = [Complex::new(0.0, 0.0); N];
+    let mut norm = 0.0;
+    for (i, row) in a.iter().enumerate() {
+        norm += row[i.saturating_sub(1)..].iter().map(|v| v.abs()).sum::<f64>();
+    }
+    // 特殊位移累计的平移量
+    let mut shift = 0.0;
+    let mut its = 0;
+    let mut end = N;
+    while end > 0 {
+        let nn = end - 1;
+        // 找到最下方的可忽略次对角元，l..=nn 为未约化的块
+        let mut l = nn;
+        while l > 0 {
+            let mut s = a[l - 1][l - 1].abs() + a[l][l].abs();
+            if s == 0.0 {
+                s = norm;
+            }
+            if a[l][l - 1].abs() <= f64::EPSILON * s {
+                a[l][l - 1] = 0.0;
+                break;
+            }
+            l -= 1;
+        }
+        let mut x = a[nn][nn];
+        if l == nn {
+            values[nn] = Complex::new(x + shift, 0.0);
+            end -= 1;
+            its = 0;
+            continue;
+        }
+        let mut y = a[nn - 1][nn - 1];
+        let mut w = a[nn][nn - 1] * a[nn - 1][nn];
+        if l == nn - 1 {
+            // 2×2 块直接求根
+            let p = 0.5 * (y - x);
+            let q = p * p + w;
+            let z = q.abs().sqrt();
+            x += shift;
+            if q >= 0.0 {
+                let z = if p >= 0.0 { p + z } else { p - z };
+                values[nn - 1] = Complex::new(x + z, 0.0);
+                values[nn] = Complex::new(if z != 0.0 { x - w / z } else { x + z }, 0.0);
+            } else {
+                values[nn - 1] = Complex::new(x + p, z);
+                values[nn] = Complex::new(x + p, -z);
+            }
+            end -= 2;
+            its = 0;
+            continue;
+        }
+        if its == MAX_QR_ITERATIONS {
+            return Err(OperationError::NotConverged {
+                algorithm: "hessenberg qr iteration",
+                iterations: MAX_QR_ITERATIONS,
+            });
+        }
+        if its == 10 || its == 20 {
+            shift += x;
+            for (i, row) in a.iter_mut().enumerate().take(nn + 1) {
+                row[i] -= x;
+            }
+            let s = a[nn][nn - 1].abs() + a[nn - 1][nn - 2].abs();
+            x = 0.75 * s;
+            y = x;
+            w = -0.4375 * s * s;
+        }
+        its += 1;
+
+        // 从下往上找两个相邻的小次对角元，确定双位移的起点 m
+        let mut m = nn - 2;
+        let (mut p, mut q, mut r);
+        loop {
+            let z = a[m][m];
+            let rr = x - z;
+            let ss = y - z;
+            p = (rr * ss - w) / a[m + 1][m] + a[m][m + 1];
+            q = a[m + 1][m + 1] - z - rr - ss;
+            r = a[m + 2][m + 1];
+            let s = p.abs() + q.abs() + r.abs();
+            p /= s;
+            q /= s;
+            r /= s;
+            if m == l {
+                break;
+            }
+            let u = a[m][m - 1].abs() * (q.abs() + r.abs());
+            let v = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+            if u <= f64::EPSILON * v {
+                break;
+            }
+            m -= 1;
+        }
+        for i in m + 2..=nn {
+            a[i][i - 2] = 0.0;
+            if i != m + 2 {
+                a[i][i - 3] = 0.0;
+            }
+        }
+        // Householder 反射把凸起沿次对角线赶出矩阵
+        for k in m..nn {
+            if k != m {
+                p = a[k][k - 1];
+                q = a[k + 1][k - 1];
+                r = if k + 1 != nn { a[k + 2][k - 1] } else { 0.0 };
+                x = p.abs() + q.abs() + r.abs();
+                if x != 0.0 {
+                    p /= x;
+                    q /= x;
+                    r /= x;
+                }
+            }
+            let norm_pqr = (p * p + q * q + r * r).sqrt();
+            let s = if p >= 0.0 { norm_pqr } else { -norm_pqr };
+            if s == 0.0 {
+                continue;
+            }
+            if k == m {
+                if l != m {
+                    a[k][k - 1] = -a[k][k - 1];
+                }
+            } else {
+                a[k][k - 1] = -s * x;
+            }
+            p += s;
+            x = p / s;
+            y = q / s;
+            let z = r / s;
+            q /= p;
+            r /= p;
+            for j in k..=nn {
+                let mut t = a[k][j] + q * a[k + 1][j];
+                if k + 1 != nn {
+                    t += r * a[k + 2][j];
+                    a[k + 2][j] -= t * z;
+                }
+                a[k + 1][j] -= t * y;
+                a[k][j] -= t * x;
+            }
+            for row in a[l..=nn.min(k + 3)].iter_mut() {
+                let mut t = x * row[k] + y * row[k + 1];
+                if k + 1 != nn {
+                    t += z * row[k + 2];
+                    row[k + 2] -= t * r;
+                }
+                row[k + 1] -= t * q;
+                row[k] -= t;
+            }
+        }
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_symmetric_eigen_diagonal() {
+        let m = Matrix::<3, 3, f64>::new([[3.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 2.0]]);
+        let eigen = m.symmetric_eigen().unwrap();
+        assert_eq!(eigen.values, [1.0, 2.0, 3.0]);
+        assert_relative_eq!(eigen.vector(0).unwrap()[1].abs(), 1.0);
+        assert_relative_eq!(eigen.vector(2).unwrap()[0].abs(), 1.0);
+    }
+
+    #[test]
+    fn test_symmetric_eigen_decomposition() {
+        let m = Matrix::<3, 3, f64>::new([[4.0, 1.0, 2.0], [1.0, 3.0, 0.5], [2.0, 0.5, 5.0]]);
+        let eigen = m.symmetric_eigen().unwrap();
+        assert!(eigen.values[0] <= eigen.values[1] && eigen.values[1] <= eigen.values[2]);
+        assert_relative_eq!(eigen.values.iter().sum::<f64>(), 12.0, epsilon = 1e-10);
+
+        let v = eigen.vectors;
+        let vtv = v.T().product(&v).unwrap();
+        let av = m.product(&v).unwrap();
+        for row in 0..3 {
+            for col in 0..3 {
+                let expected = if row == col { 1.0 } else { 0.0 };
+                assert_relative_eq!(vtv.data[row][col], expected, epsilon = 1e-10);
+                assert_relative_eq!(
+                    av.data[row][col],
+                    eigen.values[col] * v.data[row][col],
+                    epsilon = 1e-10
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_symmetric_eigen_max() {
+        let m = Matrix::<2, 2, f64>::new([[2.0, 1.0], [1.0, 2.0]]);
+        let (value, vector) = m.symmetric_eigen().unwrap().max().unwrap();
+        assert_relative_eq!(value, 3.0, epsilon = 1e-12);
+        assert_relative_eq!(vector[0].abs(), 0.5f64.sqrt(), epsilon = 1e-12);
+        assert_relative_eq!(vector[1].abs(), 0.5f64.sqrt(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_symmetric_eigen_not_symmetric() {
+        let m = Matrix::<2, 2, f64>::new([[1.0, 2.0], [3.0, 4.0]]);
+        let err = m.symmetric_eigen().unwrap_err();
+        assert_eq!(err, OperationError::NotSymmetric);
+    }
+
+    #[test]
+    fn test_eigenvalues_real() {
+        let m = Matrix::<3, 3, f64>::new([[2.0, 0.0, 0.0], [1.0, 3.0, 0.0], [4.0, 5.0, -1.0]]);
+        let values = m.eigenvalues().unwrap();
+        for (value, expected) in values.iter().zip([-1.0, 2.0, 3.0]) {
+            assert_relative_eq!(value.re, expected, epsilon = 1e-12);
+            assert_eq!(value.im, 0.0);
+        }
+
+        let m = Matrix::<2, 2, f64>::new([[1.0, 2.0], [3.0, 4.0]]);
+        let values = m.eigenvalues().unwrap();
+        assert_relative_eq!(values[0].re, (5.0 - 33f64.sqrt()) / 2.0, epsilon = 1e-12);
+        assert_relative_eq!(values[1].re, (5.0 + 33f64.sqrt()) / 2.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_eigenvalues_complex() {
+        // 绕 z 轴转 90° 的旋转矩阵：±i 与 1
+        let m = Matrix::<3, 3, f64>::new([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+        let values = m.eigenvalues().unwrap();
+        assert_relative_eq!(values[0].re, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(values[0].im, -1.0, epsilon = 1e-12);
+        assert_relative_eq!(values[1].im, 1.0, epsilon = 1e-12);
+        assert_relative_eq!(values[2].re, 1.0, epsilon = 1e-12);
+
+        // 阻尼振子 x'' + 0.4x' + 4x = 0 的状态矩阵：-0.2 ± i·√3.96
+        let a = Matrix::<2, 2, f64>::new([[0.0, 1.0], [-4.0, -0.4]]);
+        let values = a.eigenvalues().unwrap();
+        assert_relative_eq!(values[0].re, -0.2, epsilon = 1e-12);
+        assert_relative_eq!(values[1].im, 3.96f64.sqrt(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_eigenvalues_general() {
+        // 特征值之和等于迹、之积等于行列式，并且满足 det(A - λI) = 0
+        let m = Matrix::<5, 5, f64>::new([
+            [4.0, -2.0, 1.0, 0.5, 3.0],
+            [1.0, 0.0, -3.0, 2.0, 1.0],
+            [0.0, 5.0, 1.0, -1.0, 0.0],
+            [2.0, 1.0, 0.0, -2.0, 4.0],
+            [-1.0, 0.0, 2.0, 1.0, 1.0],
+        ]);
+        let values = m.eigenvalues().unwrap();
+        let sum: Complex<f64> = values.iter().sum();
+        let product: Complex<f64> = values.iter().product();
+        assert_relative_eq!(sum.re, m.trace(), epsilon = 1e-10);
+        assert_relative_eq!(sum.im, 0.0, epsilon = 1e-10);
+        assert_relative_eq!(product.re, m.det().unwrap(), max_relative = 1e-10);
+        assert_relative_eq!(product.im, 0.0, epsilon = 1e-8);
+        let with_imag = values.iter().filter(|v| v.im != 0.0).count();
+        assert_eq!(with_imag % 2, 0);
+
+        let z = Matrix::<0, 0, f64>::new([]);
+        assert_eq!(z.eigenvalues().unwrap().len(), 0);
+        assert_eq!(Matrix::<1, 1, f64>::new([[7.0]]).eigenvalues().unwrap(), [Complex::new(7.0, 0.0)]);
+    }
+
+    #[test]
+    fn test_eigenvalues_not_finite() {
+        let m = Matrix::<2, 2, f64>::new([[1.0, f64::NAN], [0.0, 2.0]]);
+        assert!(matches!(
+            m.eigenvalues(),
+            Err(OperationError::InvalidValue { name: "matrix element", value }) if value.is_nan()
+        ));
+        let m = Matrix::<2, 2, f64>::new([[1.0, 0.0], [0.0, f64::INFINITY]]);
+        assert_eq!(
+            m.eigenvalues().unwrap_err(),
+            OperationError::InvalidValue {
+                name: "matrix element",
+                value: f64::INFINITY
+            }
+        );
+    }
+}
@@ -1,33 +1,210 @@
-#[derive(Debug)]
-pub struct OperationError {
-    pub message: String,
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+// 矩阵、几何与物理量运算中的错误，每个变体携带出错时的相关数值
+#[derive(Debug, Clone, PartialEq)]
+pub enum OperationError {
+    // 矩阵形状不匹配，(行, 列)
+    DimensionMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    // 元素个数不匹配
+    LengthMismatch { expected: usize, found: usize },
+    // (行, 列) 下标越界
+    IndexOutOfBounds {
+        index: (usize, usize),
+        shape: (usize, usize),
+    },
+    RowOutOfBounds { row: usize, rows: usize },
+    ColumnOutOfBounds { col: usize, cols: usize },
+    // 从 start 开始、大小为 size 的子块超出矩阵范围
+    BlockOutOfBounds {
+        start: (usize, usize),
+        size: (usize, usize),
+        shape: (usize, usize),
+    },
+    // 奇异矩阵，condition 为 1-范数条件数估计（精确奇异时为无穷大）
+    Singular { condition: f64 },
+    // 病态矩阵，条件数超过 1/get_flt64_zero()
+    IllConditioned { condition: f64 },
+    NotSymmetric,
+    NotPositiveDefinite,
+    RankDeficient,
+    NoRealLogarithm,
+    // 迭代算法在 iterations 次内没有收敛
+    NotConverged {
+        algorithm: &'static str,
+        iterations: usize,
+    },
+    // 旋转顺序需要恰好 3 个轴
+    InvalidRotationSequence { axes: usize },
+    // 数值参数不合法，例如零范数或负的标准差
+    InvalidValue { name: &'static str, value: f64 },
+    // 随机数区间 [low, high) 为空
+    EmptyRange { low: String, high: String },
+    // 统计量至少需要 required 个样本
+    TooFewSamples { samples: usize, required: usize },
+    // 文本第 line 行无法解析
+    Parse { line: usize, text: String },
+    // 文件头缺少必需的字段，format 为 "MatrixMarket" 或 "npy"
+    MissingHeader {
+        format: &'static str,
+        field: &'static str,
+    },
+    // 文件头无法识别
+    InvalidHeader { format: &'static str, text: String },
+    // 文件头中不支持的取值，例如 MatrixMarket 的 symmetry 或 npy 的版本
+    Unsupported {
+        format: &'static str,
+        field: &'static str,
+        value: String,
+    },
+    // 文件中的元素类型与矩阵元素类型不一致
+    TypeMismatch { expected: &'static str, found: String },
+    // 数据不足，单位为字节
+    Truncated { expected: usize, found: usize },
+    Io { path: String, message: String },
 }
 
-impl OperationError {
-    pub(crate) fn new(message: &str) -> OperationError {
-        OperationError {
-            message: message.to_string(),
+impl Display for OperationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OperationError::DimensionMismatch { expected, found } => write!(
+                f,
+                "shape does not match: expected {}x{}, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+            OperationError::LengthMismatch { expected, found } => write!(
+                f,
+                "length does not match: expected {}, found {}",
+                expected, found
+            ),
+            OperationError::IndexOutOfBounds { index, shape } => write!(
+                f,
+                "index ({}, {}) out of bounds for {}x{} matrix",
+                index.0, index.1, shape.0, shape.1
+            ),
+            OperationError::RowOutOfBounds { row, rows } => {
+                write!(f, "row index {} out of bounds for {} rows", row, rows)
+            }
+            OperationError::ColumnOutOfBounds { col, cols } => {
+                write!(f, "column index {} out of bounds for {} columns", col, cols)
+            }
+            OperationError::BlockOutOfBounds { start, size, shape } => write!(
+                f,
+                "{}x{} block at ({}, {}) out of bounds for {}x{} matrix",
+                size.0, size.1, start.0, start.1, shape.0, shape.1
+            ),
+            OperationError::Singular { condition } => write!(
+                f,
+                "matrix is singular (condition number estimate: {:e})",
+                condition
+            ),
+            OperationError::IllConditioned { condition } => write!(
+                f,
+                "matrix is ill-conditioned (condition number estimate: {:e})",
+                condition
+            ),
+            OperationError::NotSymmetric => write!(f, "matrix is not symmetric"),
+            OperationError::NotPositiveDefinite => write!(f, "matrix is not positive definite"),
+            OperationError::RankDeficient => write!(f, "matrix is rank deficient"),
+            OperationError::NoRealLogarithm => write!(f, "matrix has no real principal logarithm"),
+            OperationError::NotConverged {
+                algorithm,
+                iterations,
+            } => write!(f, "{} did not converge after {} iterations", algorithm, iterations),
+            OperationError::InvalidRotationSequence { axes } => write!(
+                f,
+                "rotation sequence requires 3 axes, found {}",
+                axes
+            ),
+            OperationError::InvalidValue { name, value } => write!(f, "invalid {}: {}", name, value),
+            OperationError::EmptyRange { low, high } => {
+                write!(f, "random range [{}, {}) is empty", low, high)
+            }
+            OperationError::TooFewSamples { samples, required } => write!(
+                f,
+                "at least {} samples are required, found {}",
+                required, samples
+            ),
+            OperationError::Parse { line, text } => {
+                write!(f, "invalid number '{}' at line {}", text, line)
+            }
+            OperationError::MissingHeader { format, field } => {
+                write!(f, "{} header is missing {}", format, field)
+            }
+            OperationError::InvalidHeader { format, text } => {
+                write!(f, "invalid {} header '{}'", format, text)
+            }
+            OperationError::Unsupported {
+                format,
+                field,
+                value,
+            } => write!(f, "unsupported {} {} '{}'", format, field, value),
+            OperationError::TypeMismatch { expected, found } => write!(
+                f,
+                "element type does not match: expected '{}', found '{}'",
+                expected, found
+            ),
+            OperationError::Truncated { expected, found } => write!(
+                f,
+                "data is truncated: expected {} bytes, found {}",
+                expected, found
+            ),
+            OperationError::Io { path, message } => write!(f, "{}: {}", path, message),
         }
     }
 }
 
+impl Error for OperationError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_operation_error_new_sets_message() {
-        let err = OperationError::new("test message");
-        assert_eq!(err.message, String::from("test message"));
+    fn test_display_includes_values() {
+        let err = OperationError::DimensionMismatch {
+            expected: (2, 3),
+            found: (3, 2),
+        };
+        assert_eq!(err.to_string(), "shape does not match: expected 2x3, found 3x2");
+        let err = OperationError::IndexOutOfBounds {
+            index: (4, 1),
+            shape: (3, 3),
+        };
+        assert_eq!(err.to_string(), "index (4, 1) out of bounds for 3x3 matrix");
+        let err = OperationError::Singular {
+            condition: f64::INFINITY,
+        };
+        assert_eq!(err.to_string(), "matrix is singular (condition number estimate: inf)");
+        let err = OperationError::NotConverged {
+            algorithm: "svd",
+            iterations: 100,
+        };
+        assert_eq!(err.to_string(), "svd did not converge after 100 iterations");
+        let err = OperationError::InvalidRotationSequence { axes: 4 };
+        assert_eq!(err.to_string(), "rotation sequence requires 3 axes, found 4");
+        let err = OperationError::EmptyRange {
+            low: "1".to_string(),
+            high: "1".to_string(),
+        };
+        assert_eq!(err.to_string(), "random range [1, 1) is empty");
+        let err = OperationError::Unsupported {
+            format: "MatrixMarket",
+            field: "symmetry",
+            value: "hermitian".to_string(),
+        };
+        assert_eq!(err.to_string(), "unsupported MatrixMarket symmetry 'hermitian'");
     }
 
     #[test]
-    fn test_operation_error_new_empty_message() {
-        let err = OperationError::new("");
-        assert_eq!(err.message, String::from(""));
+    fn test_implements_error() {
+        fn fails() -> Result<(), Box<dyn Error + Send + Sync>> {
+            Err(OperationError::NotSymmetric)?;
+            Ok(())
+        }
+        assert_eq!(fails().unwrap_err().to_string(), "matrix is not symmetric");
     }
 }
-
-pub struct ValueError {
-    pub message: String,
-}
@@ -0,0 +1,201 @@
+use crate::constant::get_flt64_zero;
+use crate::dense::error::OperationError;
+use crate::dense::Matrix;
+
+// Higham (2005) 13 阶 Padé 近似的系数
+const PADE_13: [f64; 14] = [
+    64764752532480000.0,
+    32382376266240000.0,
+    7771770303897600.0,
+    1187353796428800.0,
+    129060195264000.0,
+    10559470521600.0,
+    670442572800.0,
+    33522128640.0,
+    1323241920.0,
+    40840800.0,
+    960960.0,
+    16380.0,
+    182.0,
+    1.0,
+];
+// 13 阶 Padé 近似在 ‖A‖₁ ≤ THETA_13 时达到双精度
+const THETA_13: f64 = 5.371920351148152;
+const MAX_SQRT_ITERATIONS: usize = 100;
+const MAX_SQUARE_ROOTS: usize = 64;
+const MAX_LOG_SERIES_TERMS: usize = 100;
+
+impl<const N: usize> Matrix<N, N, f64> {
+    // 矩阵指数 e^A，13 阶 Padé 近似配合缩放与平方
+    pub fn expm(&self) -> Result<Matrix<N, N, f64>, OperationError> {
+        let norm = Self::norm_1_of(&self.data);
+        if !norm.is_finite() {
+            return Err(OperationError::InvalidValue {
+                name: "matrix norm",
+                value: norm,
+            });
+        }
+        let mut squarings = 0;
+        if norm > THETA_13 {
+            squarings = (norm / THETA_13).log2().ceil() as i32;
+        }
+        let a = self.scale(0.5f64.powi(squarings));
+        let b = PADE_13;
+        let identity = Matrix::<N, N, f64>::unit();
+        let a2 = a.product(&a)?;
+        let a4 = a2.product(&a2)?;
+        let a6 = a4.product(&a2)?;
+
+        let u_high = a6.scale(b[13]) + a4.scale(b[11]) + a2.scale(b[9]);
+        let u_low = a6.scale(b[7]) + a4.scale(b[5]) + a2.scale(b[3]) + identity.scale(b[1]);
+        let u = a.product(&(a6.product(&u_high)? + u_low))?;
+        let v_high = a6.scale(b[12]) + a4.scale(b[10]) + a2.scale(b[8]);
+        let v_low = a6.scale(b[6]) + a4.scale(b[4]) + a2.scale(b[2]) + identity.scale(b[0]);
+        let v = a6.product(&v_high)? + v_low;
+
+        // (V − U)·R = V + U
+        let lu = (v - u).lu()?;
+        let rhs = v + u;
+        let mut r = [[0.0; N]; N];
+        for col in 0..N {
+            let mut b = [0.0; N];
+            for row in 0..N {
+                b[row] = rhs.data[row][col];
+            }
+            let x = lu.solve_vector(&b);
+            for row in 0..N {
+                r[row][col] = x[row];
+            }
+        }
+        let mut result = Matrix::new(r);
+        for _ in 0..squarings {
+            result = result.product(&result)?;
+        }
+        Ok(result)
+    }
+
+    // Denman–Beavers 迭代求主平方根
+    fn sqrtm_denman_beavers(&self) -> Result<Matrix<N, N, f64>, OperationError> {
+        let mut y = *self;
+        let mut z = Matrix::<N, N, f64>::unit();
+        for _ in 0..MAX_SQRT_ITERATIONS {
+            let y_inv = y.lu()?.inverse();
+            let z_inv = z.lu()?.inverse();
+            let next_y = (y + z_inv).scale(0.5);
+            let next_z = (z + y_inv).scale(0.5);
+            let diff = Self::norm_1_of(&(next_y - y).data);
+            y = next_y;
+            z = next_z;
+            if diff <= get_flt64_zero() * Self::norm_1_of(&y.data) {
+                return Ok(y);
+            }
+        }
+        Err(OperationError::NotConverged {
+            algorithm: "matrix square root",
+            iterations: MAX_SQRT_ITERATIONS,
+        })
+    }
+
+    // 矩阵主对数 log(A)，逆缩放与平方：
+    // 反复开方直到 ‖A^(1/2^k) − I‖₁ ≤ 0.1，再用 log(X) = 2·atanh((X − I)(X + I)⁻¹) 的级数
+    pub fn logm(&self) -> Result<Matrix<N, N, f64>, OperationError> {
+        let identity = Matrix::<N, N, f64>::unit();
+        let mut x = *self;
+        let mut roots: i32 = 0;
+        while Self::norm_1_of(&(x - identity).data) > 0.1 {
+            if roots as usize >= MAX_SQUARE_ROOTS {
+                return Err(OperationError::NotConverged {
+                    algorithm: "matrix logarithm",
+                    iterations: MAX_SQUARE_ROOTS,
+                });
+            }
+            x = x
+                .sqrtm_denman_beavers()
+                .map_err(|_| OperationError::NoRealLogarithm)?;
+            roots += 1;
+        }
+        let y = (x + identity).lu()?.inverse();
+        let y = (x - identity).product(&y)?;
+        let y2 = y.product(&y)?;
+        let mut term = y;
+        let mut sum = y;
+        for k in 1..MAX_LOG_SERIES_TERMS {
+            term = term.product(&y2)?;
+            let add = term.scale(1.0 / (2 * k + 1) as f64);
+            sum = sum + add;
+            if Self::norm_1_of(&add.data) <= get_flt64_zero() * Self::norm_1_of(&sum.data) {
+                break;
+            }
+        }
+        Ok(sum.scale(2.0 * 2f64.powi(roots)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn assert_matrix_eq<const N: usize>(a: &Matrix<N, N, f64>, b: &Matrix<N, N, f64>, epsilon: f64) {
+        for row in 0..N {
+            for col in 0..N {
+                assert_relative_eq!(a.data[row][col], b.data[row][col], epsilon = epsilon, max_relative = epsilon);
+            }
+        }
+    }
+
+    #[test]
+    fn test_expm_diagonal() {
+        let m = Matrix::<2, 2, f64>::new([[1.0, 0.0], [0.0, -2.0]]);
+        let e = m.expm().unwrap();
+        assert_matrix_eq(&e, &Matrix::new([[1f64.exp(), 0.0], [0.0, (-2f64).exp()]]), 1e-13);
+    }
+
+    #[test]
+    fn test_expm_rotation() {
+        // e^{[[0, −θ], [θ, 0]]} 为旋转矩阵
+        let theta = 2.5;
+        let m = Matrix::<2, 2, f64>::new([[0.0, -theta], [theta, 0.0]]);
+        let e = m.expm().unwrap();
+        let expected = Matrix::new([[theta.cos(), -theta.sin()], [theta.sin(), theta.cos()]]);
+        assert_matrix_eq(&e, &expected, 1e-13);
+    }
+
+    #[test]
+    fn test_expm_discretization() {
+        // 双积分器 ẋ = [[0, 1], [0, 0]]·x，Φ = [[1, Δt], [0, 1]]
+        let dt = 0.1;
+        let a = Matrix::<2, 2, f64>::new([[0.0, 1.0], [0.0, 0.0]]);
+        let phi = a.scale(dt).expm().unwrap();
+        assert_matrix_eq(&phi, &Matrix::new([[1.0, dt], [0.0, 1.0]]), 1e-15);
+    }
+
+    #[test]
+    fn test_expm_large_norm() {
+        let m = Matrix::<2, 2, f64>::new([[10.0, 5.0], [0.0, 10.0]]);
+        let e = m.expm().unwrap();
+        let e10 = 10f64.exp();
+        assert_matrix_eq(&e, &Matrix::new([[e10, 5.0 * e10], [0.0, e10]]), 1e-12);
+    }
+
+    #[test]
+    fn test_logm_inverts_expm() {
+        let m = Matrix::<3, 3, f64>::new([[0.1, 0.5, 0.0], [-0.5, 0.2, 0.3], [0.0, -0.3, 0.1]]);
+        let log = m.expm().unwrap().logm().unwrap();
+        assert_matrix_eq(&log, &m, 1e-10);
+    }
+
+    #[test]
+    fn test_logm_diagonal() {
+        let m = Matrix::<2, 2, f64>::new([[100.0, 0.0], [0.0, 0.01]]);
+        let log = m.logm().unwrap();
+        assert_matrix_eq(&log, &Matrix::new([[100f64.ln(), 0.0], [0.0, 0.01f64.ln()]]), 1e-10);
+        assert_relative_eq!(Matrix::<2, 2, f64>::unit().logm().unwrap().data[0][0], 0.0);
+    }
+
+    #[test]
+    fn test_logm_no_real_log() {
+        let m = Matrix::<2, 2, f64>::new([[-1.0, 0.0], [0.0, -1.0]]);
+        assert!(m.logm().is_err());
+    }
+}
@@ -0,0 +1,210 @@
+use crate::dense::util::{self, GridLayout};
+use crate::dense::Matrix;
+use std::fmt::{Alignment, Display, Formatter, LowerExp};
+
+// 单元格的格式化函数，参数为元素与精度
+type CellFormatter<T> = fn(&T, Option<usize>) -> String;
+
+fn plain<T: Display>(value: &T, precision: Option<usize>) -> String {
+    match precision {
+        Some(precision) => format!("{:.*}", precision, value),
+        None => format!("{}", value),
+    }
+}
+
+fn scientific<T: LowerExp>(value: &T, precision: Option<usize>) -> String {
+    match precision {
+        Some(precision) => format!("{:.*e}", precision, value),
+        None => format!("{:e}", value),
+    }
+}
+
+// 可配置的矩阵输出：精度、最小列宽、右对齐、科学计数法以及单行紧凑格式
+pub struct MatrixFormat<'a, const ROWS: usize, const COLS: usize, T>
+where
+    T: Copy + Send + Sync,
+{
+    matrix: &'a Matrix<ROWS, COLS, T>,
+    precision: Option<usize>,
+    layout: GridLayout,
+    cell: CellFormatter<T>,
+}
+
+impl<'a, const ROWS: usize, const COLS: usize, T> MatrixFormat<'a, ROWS, COLS, T>
+where
+    T: Copy + Send + Sync + Display,
+{
+    // 保留的小数位数
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    // 单元格的最小宽度
+    pub fn width(mut self, width: usize) -> Self {
+        self.layout.width = width;
+        self
+    }
+
+    pub fn right_align(mut self) -> Self {
+        self.layout.right_align = true;
+        self
+    }
+
+    // 单行输出，例如 [[1, 2], [3, 4]]，适合写日志
+    pub fn compact(mut self) -> Self {
+        self.layout.compact = true;
+        self
+    }
+
+    // 按 Formatter 的精度、宽度与对齐方式覆盖当前选项
+    fn with_flags(mut self, f: &Formatter<'_>) -> Self {
+        apply_flags(f, &mut self.precision, &mut self.layout);
+        self
+    }
+}
+
+fn apply_flags(f: &Formatter<'_>, precision: &mut Option<usize>, layout: &mut GridLayout) {
+    if let Some(p) = f.precision() {
+        *precision = Some(p);
+    }
+    if let Some(width) = f.width() {
+        layout.width = width;
+    }
+    if let Some(Alignment::Right) = f.align() {
+        layout.right_align = true;
+    }
+}
+
+fn render<T>(
+    data: &[T],
+    rows: usize,
+    cols: usize,
+    cell: CellFormatter<T>,
+    precision: Option<usize>,
+    layout: &GridLayout,
+) -> String {
+    let cells: Vec<String> = data.iter().map(|value| cell(value, precision)).collect();
+    util::render_grid(&cells, rows, cols, layout)
+}
+
+// 按行优先存储的数据按 Formatter 的精度、宽度与对齐方式输出，DMatrix 与 Matrix 的输出一致
+pub(crate) fn fmt_slice_with_flags<T: Display>(
+    data: &[T],
+    rows: usize,
+    cols: usize,
+    f: &mut Formatter<'_>,
+) -> std::fmt::Result {
+    let mut precision = None;
+    let mut layout = GridLayout::default();
+    apply_flags(f, &mut precision, &mut layout);
+    write!(f, "{}", render(data, rows, cols, plain::<T>, precision, &layout))
+}
+
+impl<'a, const ROWS: usize, const COLS: usize, T> MatrixFormat<'a, ROWS, COLS, T>
+where
+    T: Copy + Send + Sync + Display + LowerExp,
+{
+    // 科学计数法，例如 1.5e3
+    pub fn scientific(mut self) -> Self {
+        self.cell = scientific::<T>;
+        self
+    }
+}
+
+impl<'a, const ROWS: usize, const COLS: usize, T> Display for MatrixFormat<'a, ROWS, COLS, T>
+where
+    T: Copy + Send + Sync + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let data = self.matrix.data.as_flattened();
+        write!(f, "{}", render(data, ROWS, COLS, self.cell, self.precision, &self.layout))
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, T> Matrix<ROWS, COLS, T>
+where
+    T: Copy + Send + Sync + Display,
+{
+    // 以 set_digits 设置的精度为默认值的格式化选项
+    pub fn formatted(&self) -> MatrixFormat<'_, ROWS, COLS, T> {
+        MatrixFormat {
+            matrix: self,
+            precision: if self.digits > 0 { Some(self.digits as usize) } else { None },
+            layout: GridLayout::default(),
+            cell: plain::<T>,
+        }
+    }
+
+    // 设置默认输出精度，0 表示按元素自身的最短表示输出
+    pub fn set_digits(&mut self, digits: u8) {
+        self.digits = digits;
+    }
+
+    pub fn digits(&self) -> u8 {
+        self.digits
+    }
+
+    pub(crate) fn fmt_with_flags(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.formatted().with_flags(f))
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, T> LowerExp for Matrix<ROWS, COLS, T>
+where
+    T: Copy + Send + Sync + Display + LowerExp,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.formatted().scientific().with_flags(f))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_precision_flag() {
+        let m = Matrix::<2, 2, f64>::new([[1.0, 2.34567], [-3.5, 4.0]]);
+        assert_eq!(format!("{:.2}", m), "┌1.00   2.35   ┐\n└-3.50  4.00   ┘\n");
+        assert_eq!(format!("{:.0}", m), "┌1   2   ┐\n└-4  4   ┘\n");
+    }
+
+    #[test]
+    fn test_width_and_alignment() {
+        let m = Matrix::<2, 2, i32>::new([[1, 20], [300, 4]]);
+        assert_eq!(format!("{:>}", m), "┌    1   20┐\n└  300    4┘\n");
+        assert_eq!(format!("{:5}", m), "┌1      20     ┐\n└300    4      ┘\n");
+        assert_eq!(format!("{:>5}", m), "┌      1     20┐\n└    300      4┘\n");
+        let row = Matrix::<1, 2, i32>::new([[1, 20]]);
+        assert_eq!(row.formatted().right_align().to_string(), "[   1  20]\n");
+    }
+
+    #[test]
+    fn test_scientific() {
+        let m = Matrix::<2, 2, f64>::new([[1500.0, 0.00025], [-2.0, 1.0]]);
+        assert_eq!(format!("{:.2e}", m), "┌1.50e3   2.50e-4  ┐\n└-2.00e0  1.00e0   ┘\n");
+        assert_eq!(format!("{:e}", m), format!("{}", m.formatted().scientific()));
+        assert_eq!(m.formatted().scientific().compact().to_string(), "[[1.5e3, 2.5e-4], [-2e0, 1e0]]");
+    }
+
+    #[test]
+    fn test_compact() {
+        let m = Matrix::<2, 3, f64>::new([[1.0, 2.5, 3.0], [4.0, 5.0, 6.125]]);
+        assert_eq!(m.formatted().compact().to_string(), "[[1, 2.5, 3], [4, 5, 6.125]]");
+        assert_eq!(
+            m.formatted().compact().precision(1).to_string(),
+            "[[1.0, 2.5, 3.0], [4.0, 5.0, 6.1]]"
+        );
+        assert_eq!(Matrix::<0, 0, f64>::new([]).formatted().compact().to_string(), "[]");
+    }
+
+    #[test]
+    fn test_digits() {
+        let mut m = Matrix::<1, 2, f64>::new([[1.0, 2.0 / 3.0]]);
+        assert_eq!(m.digits(), 0);
+        m.set_digits(3);
+        assert_eq!(format!("{}", m), "[1.000  0.667  ]\n");
+        assert_eq!(format!("{:.1}", m), "[1.0  0.7  ]\n");
+    }
+}
@@ -1,6 +1,8 @@
 use crate::dense::{error, Matrix};
 use array_init::array_init;
+use rand::distr::uniform::SampleUniform;
 use rand::Rng;
+use std::cmp::Ordering;
 use std::fmt::Display;
 use std::ops::{Add, Mul, Sub};
 
@@ -49,21 +51,103 @@ where
 }
 
 impl<const ROWS: usize, const COLS: usize> Matrix<ROWS, COLS, f64> {
+    // [0, 1) 均匀分布的随机矩阵
     pub fn random() -> Self {
-        let mut rng = rand::rng();
-        let mut data = [[0.0; COLS]; ROWS];
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                data[row][col] = rng.random();
+        Self::random_with_rng(&mut rand::rng())
+    }
+
+    // 使用指定的随机数发生器，传入带种子的发生器可以得到可复现的结果
+    pub fn random_with_rng<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let data = array_init(|_| array_init(|_| rng.random()));
+        Matrix { data, digits: 0 }
+    }
+
+    // 正态分布 N(mean, std_dev²) 的随机矩阵，Box–Muller 变换
+    pub fn random_normal<R: Rng + ?Sized>(
+        rng: &mut R,
+        mean: f64,
+        std_dev: f64,
+    ) -> Result<Self, error::OperationError> {
+        if !mean.is_finite() || !std_dev.is_finite() || std_dev < 0.0 {
+            return Err(error::OperationError::InvalidValue {
+                name: "normal distribution parameter",
+                value: if mean.is_finite() { std_dev } else { mean },
+            });
+        }
+        let data = array_init(|_| array_init(|_| mean + std_dev * standard_normal(rng)));
+        Ok(Matrix { data, digits: 0 })
+    }
+}
+
+// 标准正态分布的一个样本
+fn standard_normal<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    // u1 ∈ (0, 1]，避免 ln(0)
+    let u1 = 1.0 - rng.random::<f64>();
+    let u2: f64 = rng.random();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+impl<const ROWS: usize, const COLS: usize, T> Matrix<ROWS, COLS, T>
+where
+    T: Copy + Send + Sync + PartialOrd + SampleUniform + Display,
+{
+    // [low, high) 均匀分布的随机矩阵，整数与浮点类型均可使用
+    pub fn random_range<R: Rng + ?Sized>(
+        rng: &mut R,
+        low: T,
+        high: T,
+    ) -> Result<Self, error::OperationError> {
+        if low.partial_cmp(&high) != Some(Ordering::Less) {
+            return Err(error::OperationError::EmptyRange {
+                low: low.to_string(),
+                high: high.to_string(),
+            });
+        }
+        let data = array_init(|_| array_init(|_| rng.random_range(low..high)));
+        Ok(Matrix { data, digits: 0 })
+    }
+}
+
+impl<const N: usize> Matrix<N, N, f64> {
+    // 随机对称正定矩阵 A·Aᵀ + N·I，A 的元素服从标准正态分布
+    pub fn random_spd<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let a: [[f64; N]; N] = array_init(|_| array_init(|_| standard_normal(rng)));
+        let mut data = [[0.0; N]; N];
+        for i in 0..N {
+            for j in 0..=i {
+                let dot: f64 = (0..N).map(|k| a[i][k] * a[j][k]).sum();
+                data[i][j] = dot;
+                data[j][i] = dot;
             }
+            data[i][i] += N as f64;
         }
         Matrix { data, digits: 0 }
     }
+
+    // 服从 Haar 分布的随机正交矩阵：对标准正态矩阵做 QR 分解，再按 R 的对角线符号修正 Q
+    pub fn random_orthogonal<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let a = Matrix::<N, N, f64> {
+            data: array_init(|_| array_init(|_| standard_normal(rng))),
+            digits: 0,
+        };
+        let qr = a.qr();
+        let mut q = qr.q;
+        for col in 0..N {
+            if qr.r.data[col][col] < 0.0 {
+                for row in 0..N {
+                    q.data[row][col] = -q.data[row][col];
+                }
+            }
+        }
+        q
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     #[test]
     fn test_zeros() {
@@ -96,4 +180,59 @@ mod tests {
         println!("{}", m);
         assert_eq!(m.size(), (3, 3));
     }
+
+    #[test]
+    fn test_random_with_rng_is_reproducible() {
+        let a = Matrix::<3, 4, f64>::random_with_rng(&mut StdRng::seed_from_u64(42));
+        let b = Matrix::<3, 4, f64>::random_with_rng(&mut StdRng::seed_from_u64(42));
+        assert_eq!(a.data, b.data);
+        assert!(a.iter().all(|&x| (0.0..1.0).contains(&x)));
+    }
+
+    #[test]
+    fn test_random_range() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let m = Matrix::<10, 10, i32>::random_range(&mut rng, -3, 4).unwrap();
+        assert!(m.iter().all(|&x| (-3..4).contains(&x)));
+        let m = Matrix::<10, 10, f64>::random_range(&mut rng, 2.0, 2.5).unwrap();
+        assert!(m.iter().all(|&x| (2.0..2.5).contains(&x)));
+        assert_eq!(
+            Matrix::<2, 2, i32>::random_range(&mut rng, 1, 1).unwrap_err(),
+            error::OperationError::EmptyRange {
+                low: "1".to_string(),
+                high: "1".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_random_normal() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let m = Matrix::<100, 100, f64>::random_normal(&mut rng, 3.0, 2.0).unwrap();
+        let n = 10000.0;
+        let mean = m.sum() / n;
+        let variance = m.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        assert!((mean - 3.0).abs() < 0.1);
+        assert!((variance.sqrt() - 2.0).abs() < 0.1);
+        assert!(Matrix::<2, 2, f64>::random_normal(&mut rng, 0.0, -1.0).is_err());
+    }
+
+    #[test]
+    fn test_random_spd() {
+        let m = Matrix::<5, 5, f64>::random_spd(&mut StdRng::seed_from_u64(3));
+        assert_eq!(m.data, m.T().data);
+        assert!(m.cholesky().is_ok());
+    }
+
+    #[test]
+    fn test_random_orthogonal() {
+        let q = Matrix::<4, 4, f64>::random_orthogonal(&mut StdRng::seed_from_u64(5));
+        let qtq = q.T().product(&q).unwrap();
+        for row in 0..4 {
+            for col in 0..4 {
+                let expected = if row == col { 1.0 } else { 0.0 };
+                assert!((qtq.data[row][col] - expected).abs() < 1e-12);
+            }
+        }
+    }
 }
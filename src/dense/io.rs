@@ -0,0 +1,663 @@
+use crate::dense::error::OperationError;
+use crate::dense::Matrix;
+use array_init::array_init;
+use std::fmt::Display;
+use std::fs;
+use std::ops::Neg;
+use std::path::Path;
+use std::str::FromStr;
+
+const NPY_MAGIC: &[u8] = b"\x93NUMPY";
+// npy 头部（含魔数与长度字段）按 64 字节对齐
+const NPY_ALIGNMENT: usize = 64;
+
+// 可以读写 CSV、MatrixMarket 与 npy 的元素类型
+pub trait IoElement: Copy + Default + Send + Sync + Display + FromStr + Neg<Output = Self> {
+    // npy 中的 dtype 描述，例如 "<f8"
+    const NPY_DESCR: &'static str;
+    // 每个元素占用的字节数
+    const NPY_SIZE: usize;
+    // 写出 MatrixMarket 时使用的 field
+    const MATRIX_MARKET_FIELD: &'static str;
+
+    // 读取 MatrixMarket 时是否接受该 field
+    fn accepts_field(field: &str) -> bool;
+
+    fn from_le_slice(bytes: &[u8]) -> Self;
+
+    fn write_le(&self, out: &mut Vec<u8>);
+}
+
+macro_rules! impl_io_element {
+    ($t:ty, $descr:expr, $field:expr, [$($accepted:expr),*]) => {
+        impl IoElement for $t {
+            const NPY_DESCR: &'static str = $descr;
+            const NPY_SIZE: usize = std::mem::size_of::<$t>();
+            const MATRIX_MARKET_FIELD: &'static str = $field;
+
+            fn accepts_field(field: &str) -> bool {
+                matches!(field, $($accepted)|*)
+            }
+
+            fn from_le_slice(bytes: &[u8]) -> Self {
+                let mut buf = [0u8; std::mem::size_of::<$t>()];
+                buf.copy_from_slice(bytes);
+                <$t>::from_le_bytes(buf)
+            }
+
+            fn write_le(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+    };
+}
+
+impl_io_element!(f64, "<f8", "real", ["real", "double", "integer"]);
+impl_io_element!(f32, "<f4", "real", ["real", "double", "integer"]);
+impl_io_element!(i32, "<i4", "integer", ["integer"]);
+
+fn shape_error(rows: usize, cols: usize, found_rows: usize, found_cols: usize) -> OperationError {
+    OperationError::DimensionMismatch {
+        expected: (rows, cols),
+        found: (found_rows, found_cols),
+    }
+}
+
+fn parse_value<T: IoElement>(text: &str, line: usize) -> Result<T, OperationError> {
+    text.parse::<T>().map_err(|_| OperationError::Parse {
+        line,
+        text: text.to_string(),
+    })
+}
+
+fn read_text(path: &Path) -> Result<String, OperationError> {
+    fs::read_to_string(path).map_err(|e| io_error(path, e))
+}
+
+fn write_bytes(path: &Path, bytes: &[u8]) -> Result<(), OperationError> {
+    fs::write(path, bytes).map_err(|e| io_error(path, e))
+}
+
+fn io_error(path: &Path, error: std::io::Error) -> OperationError {
+    OperationError::Io {
+        path: path.display().to_string(),
+        message: error.to_string(),
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, T> Matrix<ROWS, COLS, T>
+where
+    T: IoElement,
+{
+    // 按行主序的元素构造矩阵，调用方保证长度为 ROWS·COLS
+    fn from_row_major(values: &[T]) -> Self {
+        let data = array_init(|row| array_init(|col| values[row * COLS + col]));
+        Matrix { data, digits: 0 }
+    }
+
+    // 解析逗号分隔的文本，每行一个矩阵行，忽略空行和以 # 开头的注释行
+    pub fn from_csv_str(text: &str) -> Result<Self, OperationError> {
+        let mut values = Vec::with_capacity(ROWS * COLS);
+        let mut rows = 0;
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            if fields.len() != COLS {
+                return Err(shape_error(ROWS, COLS, rows + 1, fields.len()));
+            }
+            rows += 1;
+            if rows > ROWS {
+                continue;
+            }
+            for field in fields {
+                values.push(parse_value(field, index + 1)?);
+            }
+        }
+        if rows != ROWS {
+            return Err(shape_error(ROWS, COLS, rows, COLS));
+        }
+        Ok(Self::from_row_major(&values))
+    }
+
+    pub fn to_csv_string(&self) -> String {
+        let mut text = String::new();
+        for row in self.data.iter() {
+            let line: Vec<String> = row.iter().map(|value| value.to_string()).collect();
+            text.push_str(&line.join(","));
+            text.push('\n');
+        }
+        text
+    }
+
+    pub fn read_csv<P: AsRef<Path>>(path: P) -> Result<Self, OperationError> {
+        Self::from_csv_str(&read_text(path.as_ref())?)
+    }
+
+    pub fn write_csv<P: AsRef<Path>>(&self, path: P) -> Result<(), OperationError> {
+        write_bytes(path.as_ref(), self.to_csv_string().as_bytes())
+    }
+
+    // 解析 MatrixMarket 文本，支持 array 与 coordinate 格式以及 general、symmetric、skew-symmetric
+    pub fn from_matrix_market_str(text: &str) -> Result<Self, OperationError> {
+        let mut lines = text.lines().enumerate();
+        let (banner, header) = match lines.next() {
+            Some((_, line)) => (line, line.to_lowercase()),
+            None => {
+                return Err(OperationError::MissingHeader {
+                    format: "MatrixMarket",
+                    field: "banner",
+                })
+            }
+        };
+        let tokens: Vec<&str> = header.split_whitespace().collect();
+        if tokens.len() != 5 || tokens[0] != "%%matrixmarket" || tokens[1] != "matrix" {
+            return Err(OperationError::InvalidHeader {
+                format: "MatrixMarket",
+                text: banner.to_string(),
+            });
+        }
+        let (format, field, symmetry) = (tokens[2], tokens[3], tokens[4]);
+        if !T::accepts_field(field) {
+            return Err(OperationError::Unsupported {
+                format: "MatrixMarket",
+                field: "field",
+                value: field.to_string(),
+            });
+        }
+        if !matches!(symmetry, "general" | "symmetric" | "skew-symmetric") {
+            return Err(OperationError::Unsupported {
+                format: "MatrixMarket",
+                field: "symmetry",
+                value: symmetry.to_string(),
+            });
+        }
+        let mut entries = lines.filter(|(_, line)| {
+            let line = line.trim();
+            !line.is_empty() && !line.starts_with('%')
+        });
+
+        let (size_line, size_text) = match entries.next() {
+            Some((index, line)) => (index + 1, line),
+            None => {
+                return Err(OperationError::MissingHeader {
+                    format: "MatrixMarket",
+                    field: "size line",
+                })
+            }
+        };
+        let size: Vec<&str> = size_text.split_whitespace().collect();
+        let expected_size = if format == "coordinate" { 3 } else { 2 };
+        if size.len() != expected_size {
+            return Err(OperationError::Parse {
+                line: size_line,
+                text: size_text.to_string(),
+            });
+        }
+        let mut dims = [0usize; 3];
+        for (dim, text) in dims.iter_mut().zip(size.iter()) {
+            *dim = text.parse().map_err(|_| OperationError::Parse {
+                line: size_line,
+                text: text.to_string(),
+            })?;
+        }
+        if dims[0] != ROWS || dims[1] != COLS {
+            return Err(shape_error(ROWS, COLS, dims[0], dims[1]));
+        }
+        if symmetry != "general" && ROWS != COLS {
+            // 对称存储要求方阵
+            return Err(OperationError::DimensionMismatch {
+                expected: (ROWS, ROWS),
+                found: (ROWS, COLS),
+            });
+        }
+
+        let mut values = vec![T::default(); ROWS * COLS];
+        let mut set = |row: usize, col: usize, value: T| {
+            values[row * COLS + col] = value;
+            if row != col {
+                match symmetry {
+                    "symmetric" => values[col * COLS + row] = value,
+                    "skew-symmetric" => values[col * COLS + row] = -value,
+                    _ => {}
+                }
+            }
+        };
+        match format {
+            "array" => {
+                // 按列主序存储，对称矩阵只存下三角（反对称矩阵不含对角线）
+                let mut positions = Vec::with_capacity(ROWS * COLS);
+                for col in 0..COLS {
+                    let start = match symmetry {
+                        "general" => 0,
+                        "symmetric" => col,
+                        _ => col + 1,
+                    };
+                    for row in start..ROWS {
+                        positions.push((row, col));
+                    }
+                }
+                let mut count = 0;
+                for (index, line) in entries {
+                    for text in line.split_whitespace() {
+                        let value = parse_value(text, index + 1)?;
+                        if let Some(&(row, col)) = positions.get(count) {
+                            set(row, col, value);
+                        }
+                        count += 1;
+                    }
+                }
+                if count != positions.len() {
+                    return Err(OperationError::LengthMismatch {
+                        expected: positions.len(),
+                        found: count,
+                    });
+                }
+            }
+            "coordinate" => {
+                let nonzeros = dims[2];
+                let mut count = 0;
+                for (index, line) in entries {
+                    let fields: Vec<&str> = line.split_whitespace().collect();
+                    let invalid = || OperationError::Parse {
+                        line: index + 1,
+                        text: line.to_string(),
+                    };
+                    if fields.len() != 3 {
+                        return Err(invalid());
+                    }
+                    // 下标从 1 开始
+                    let row: usize = fields[0].parse().map_err(|_| invalid())?;
+                    let col: usize = fields[1].parse().map_err(|_| invalid())?;
+                    if row == 0 || col == 0 {
+                        return Err(invalid());
+                    }
+                    if row > ROWS || col > COLS {
+                        return Err(OperationError::IndexOutOfBounds {
+                            index: (row - 1, col - 1),
+                            shape: (ROWS, COLS),
+                        });
+                    }
+                    set(row - 1, col - 1, parse_value(fields[2], index + 1)?);
+                    count += 1;
+                }
+                if count != nonzeros {
+                    return Err(OperationError::LengthMismatch {
+                        expected: nonzeros,
+                        found: count,
+                    });
+                }
+            }
+            _ => {
+                return Err(OperationError::Unsupported {
+                    format: "MatrixMarket",
+                    field: "format",
+                    value: format.to_string(),
+                })
+            }
+        }
+        Ok(Self::from_row_major(&values))
+    }
+
+    // 以 array general 格式输出（列主序）
+    pub fn to_matrix_market_string(&self) -> String {
+        let mut text = format!(
+            "%%MatrixMarket matrix array {} general\n{} {}\n",
+            T::MATRIX_MARKET_FIELD,
+            ROWS,
+            COLS
+        );
+        for col in 0..COLS {
+            for row in self.data.iter() {
+                text.push_str(&row[col].to_string());
+                text.push('\n');
+            }
+        }
+        text
+    }
+
+    pub fn read_matrix_market<P: AsRef<Path>>(path: P) -> Result<Self, OperationError> {
+        Self::from_matrix_market_str(&read_text(path.as_ref())?)
+    }
+
+    pub fn write_matrix_market<P: AsRef<Path>>(&self, path: P) -> Result<(), OperationError> {
+        write_bytes(path.as_ref(), self.to_matrix_market_string().as_bytes())
+    }
+
+    // 解析 NumPy .npy 格式（1.0/2.0/3.0 版本），一维数组按行向量或列向量读取
+    pub fn from_npy_bytes(bytes: &[u8]) -> Result<Self, OperationError> {
+        if bytes.len() < 10 || &bytes[..6] != NPY_MAGIC {
+            return Err(OperationError::InvalidHeader {
+                format: "npy",
+                text: String::from_utf8_lossy(&bytes[..bytes.len().min(6)]).into_owned(),
+            });
+        }
+        let (header_len, header_start) = match bytes[6] {
+            1 => (u16::from_le_bytes([bytes[8], bytes[9]]) as usize, 10),
+            2 | 3 if bytes.len() >= 12 => (
+                u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize,
+                12,
+            ),
+            version => {
+                return Err(OperationError::Unsupported {
+                    format: "npy",
+                    field: "version",
+                    value: version.to_string(),
+                })
+            }
+        };
+        let data_start = header_start + header_len;
+        if bytes.len() < data_start {
+            return Err(OperationError::Truncated {
+                expected: data_start,
+                found: bytes.len(),
+            });
+        }
+        let header = std::str::from_utf8(&bytes[header_start..data_start])
+            .map_err(|_| OperationError::InvalidHeader {
+                format: "npy",
+                text: String::from_utf8_lossy(&bytes[header_start..data_start]).into_owned(),
+            })?;
+
+        let descr = npy_header_value(header, "descr")
+            .map(|value| value.trim_matches(|c| c == '\'' || c == '"'))
+            .ok_or(OperationError::MissingHeader {
+                format: "npy",
+                field: "descr",
+            })?;
+        if descr != T::NPY_DESCR {
+            return Err(OperationError::TypeMismatch {
+                expected: T::NPY_DESCR,
+                found: descr.to_string(),
+            });
+        }
+        let fortran_order = match npy_header_value(header, "fortran_order") {
+            Some("True") => true,
+            Some("False") => false,
+            _ => {
+                return Err(OperationError::MissingHeader {
+                    format: "npy",
+                    field: "fortran_order",
+                })
+            }
+        };
+        let shape = npy_header_value(header, "shape")
+            .ok_or(OperationError::MissingHeader {
+                format: "npy",
+                field: "shape",
+            })?;
+        let dims: Vec<usize> = shape
+            .trim_matches(|c| c == '(' || c == ')')
+            .split(',')
+            .map(str::trim)
+            .filter(|dim| !dim.is_empty())
+            .map(|dim| dim.parse::<usize>())
+            .collect::<Result<_, _>>()
+            .map_err(|_| OperationError::InvalidHeader {
+                format: "npy",
+                text: shape.to_string(),
+            })?;
+        let (rows, cols) = match dims.as_slice() {
+            [] => (1, 1),
+            [n] if ROWS == 1 => (1, *n),
+            [n] => (*n, 1),
+            [rows, cols] => (*rows, *cols),
+            _ => {
+                return Err(OperationError::Unsupported {
+                    format: "npy",
+                    field: "ndim",
+                    value: dims.len().to_string(),
+                })
+            }
+        };
+        if rows != ROWS || cols != COLS {
+            return Err(shape_error(ROWS, COLS, rows, cols));
+        }
+
+        let data = &bytes[data_start..];
+        if data.len() < ROWS * COLS * T::NPY_SIZE {
+            return Err(OperationError::Truncated {
+                expected: ROWS * COLS * T::NPY_SIZE,
+                found: data.len(),
+            });
+        }
+        let mut values = Vec::with_capacity(ROWS * COLS);
+        for row in 0..ROWS {
+            for col in 0..COLS {
+                let index = if fortran_order { col * ROWS + row } else { row * COLS + col };
+                let offset = index * T::NPY_SIZE;
+                values.push(T::from_le_slice(&data[offset..offset + T::NPY_SIZE]));
+            }
+        }
+        Ok(Self::from_row_major(&values))
+    }
+
+    // 输出 1.0 版本的 .npy，C 顺序，形状为 (ROWS, COLS)
+    pub fn to_npy_bytes(&self) -> Vec<u8> {
+        let mut header = format!(
+            "{{'descr': '{}', 'fortran_order': False, 'shape': ({}, {}), }}",
+            T::NPY_DESCR,
+            ROWS,
+            COLS
+        );
+        let unpadded = NPY_MAGIC.len() + 4 + header.len() + 1;
+        let padding = (NPY_ALIGNMENT - unpadded % NPY_ALIGNMENT) % NPY_ALIGNMENT;
+        header.push_str(&" ".repeat(padding));
+        header.push('\n');
+
+        let mut bytes = Vec::with_capacity(10 + header.len() + ROWS * COLS * T::NPY_SIZE);
+        bytes.extend_from_slice(NPY_MAGIC);
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        for row in self.data.iter() {
+            for value in row.iter() {
+                value.write_le(&mut bytes);
+            }
+        }
+        bytes
+    }
+
+    pub fn read_npy<P: AsRef<Path>>(path: P) -> Result<Self, OperationError> {
+        let path = path.as_ref();
+        let bytes = fs::read(path).map_err(|e| io_error(path, e))?;
+        Self::from_npy_bytes(&bytes)
+    }
+
+    pub fn write_npy<P: AsRef<Path>>(&self, path: P) -> Result<(), OperationError> {
+        write_bytes(path.as_ref(), &self.to_npy_bytes())
+    }
+}
+
+// 从 npy 头部的 Python 字典字面量中取出 key 对应的值文本
+fn npy_header_value<'a>(header: &'a str, key: &str) -> Option<&'a str> {
+    let pattern = format!("'{}':", key);
+    let start = header.find(&pattern)? + pattern.len();
+    let rest = header[start..].trim_start();
+    let end = if rest.starts_with('(') {
+        rest.find(')')? + 1
+    } else if rest.starts_with('\'') || rest.starts_with('"') {
+        rest[1..].find(['\'', '"'])? + 2
+    } else {
+        rest.find([',', '}'])?
+    };
+    Some(rest[..end].trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn npy_with_header(header: &str, payload: &[u8]) -> Vec<u8> {
+        let mut bytes = NPY_MAGIC.to_vec();
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let m = Matrix::<2, 3, f64>::new([[1.0, -2.5, 3.0], [0.1, 5e-20, 6.0]]);
+        let text = m.to_csv_string();
+        assert_eq!(text, "1,-2.5,3\n0.1,0.00000000000000000005,6\n");
+        let back = Matrix::<2, 3, f64>::from_csv_str(&text).unwrap();
+        assert_eq!(back.data, m.data);
+
+        let parsed = Matrix::<2, 2, i32>::from_csv_str("# header\n1, 2\n\n3 ,4\n").unwrap();
+        assert_eq!(parsed.data, [[1, 2], [3, 4]]);
+    }
+
+    #[test]
+    fn test_csv_shape_mismatch() {
+        let err = Matrix::<2, 2, f64>::from_csv_str("1,2\n3,4\n5,6\n").unwrap_err();
+        assert_eq!(err.to_string(), "shape does not match: expected 2x2, found 3x2");
+        let err = Matrix::<2, 2, f64>::from_csv_str("1,2\n3\n").unwrap_err();
+        assert_eq!(err.to_string(), "shape does not match: expected 2x2, found 2x1");
+        let err = Matrix::<2, 2, f64>::from_csv_str("1,2\n3,x\n").unwrap_err();
+        assert_eq!(err.to_string(), "invalid number 'x' at line 2");
+    }
+
+    #[test]
+    fn test_matrix_market_round_trip() {
+        let m = Matrix::<2, 3, f32>::new([[1.0, 2.0, 3.0], [4.0, 5.5, 6.0]]);
+        let text = m.to_matrix_market_string();
+        assert!(text.starts_with("%%MatrixMarket matrix array real general\n2 3\n1\n4\n2\n"));
+        let back = Matrix::<2, 3, f32>::from_matrix_market_str(&text).unwrap();
+        assert_eq!(back.data, m.data);
+
+        let err = Matrix::<3, 2, f32>::from_matrix_market_str(&text).unwrap_err();
+        assert_eq!(err.to_string(), "shape does not match: expected 3x2, found 2x3");
+    }
+
+    #[test]
+    fn test_matrix_market_coordinate_symmetric() {
+        let text = "%%MatrixMarket matrix coordinate integer symmetric\n\
+                    % comment\n\
+                    3 3 4\n\
+                    1 1 2\n\
+                    2 1 -1\n\
+                    3 2 5\n\
+                    3 3 7\n";
+        let m = Matrix::<3, 3, i32>::from_matrix_market_str(text).unwrap();
+        assert_eq!(m.data, [[2, -1, 0], [-1, 0, 5], [0, 5, 7]]);
+
+        let real = "%%MatrixMarket matrix coordinate real general\n2 2 1\n1 1 1.5\n";
+        assert!(Matrix::<2, 2, i32>::from_matrix_market_str(real).is_err());
+        assert_eq!(Matrix::<2, 2, f64>::from_matrix_market_str(real).unwrap().data, [[1.5, 0.0], [0.0, 0.0]]);
+    }
+
+    #[test]
+    fn test_matrix_market_array_skew_symmetric() {
+        let text = "%%MatrixMarket matrix array real skew-symmetric\n3 3\n1\n2\n3\n";
+        let m = Matrix::<3, 3, f64>::from_matrix_market_str(text).unwrap();
+        assert_eq!(m.data, [[0.0, -1.0, -2.0], [1.0, 0.0, -3.0], [2.0, 3.0, 0.0]]);
+    }
+
+    #[test]
+    fn test_matrix_market_errors() {
+        let err = Matrix::<2, 2, f64>::from_matrix_market_str("").unwrap_err();
+        assert_eq!(
+            err,
+            OperationError::MissingHeader {
+                format: "MatrixMarket",
+                field: "banner"
+            }
+        );
+        let text = "%%MatrixMarket matrix array real hermitian\n2 2\n";
+        assert_eq!(
+            Matrix::<2, 2, f64>::from_matrix_market_str(text).unwrap_err(),
+            OperationError::Unsupported {
+                format: "MatrixMarket",
+                field: "symmetry",
+                value: "hermitian".to_string()
+            }
+        );
+        let text = "%%MatrixMarket matrix array real general\n2 2\n1\n2\n3\n";
+        assert_eq!(
+            Matrix::<2, 2, f64>::from_matrix_market_str(text).unwrap_err(),
+            OperationError::LengthMismatch {
+                expected: 4,
+                found: 3
+            }
+        );
+        let text = "%%MatrixMarket matrix coordinate real general\n2 2 1\n3 1 1.0\n";
+        assert_eq!(
+            Matrix::<2, 2, f64>::from_matrix_market_str(text).unwrap_err(),
+            OperationError::IndexOutOfBounds {
+                index: (2, 0),
+                shape: (2, 2)
+            }
+        );
+    }
+
+    #[test]
+    fn test_npy_round_trip() {
+        let m = Matrix::<2, 3, f64>::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let bytes = m.to_npy_bytes();
+        assert_eq!((bytes.len() - 2 * 3 * 8) % NPY_ALIGNMENT, 0);
+        assert_eq!(bytes[bytes.len() - 2 * 3 * 8 - 1], b'\n');
+        assert_eq!(Matrix::<2, 3, f64>::from_npy_bytes(&bytes).unwrap().data, m.data);
+
+        let m = Matrix::<2, 2, i32>::new([[1, -2], [3, 4]]);
+        assert_eq!(Matrix::<2, 2, i32>::from_npy_bytes(&m.to_npy_bytes()).unwrap().data, m.data);
+        assert_eq!(
+            Matrix::<2, 2, f32>::from_npy_bytes(&m.to_npy_bytes()).unwrap_err(),
+            OperationError::TypeMismatch {
+                expected: "<f4",
+                found: "<i4".to_string()
+            }
+        );
+        let err = Matrix::<1, 4, i32>::from_npy_bytes(&m.to_npy_bytes()).unwrap_err();
+        assert_eq!(err.to_string(), "shape does not match: expected 1x4, found 2x2");
+    }
+
+    #[test]
+    fn test_npy_fortran_order_and_vector() {
+        let mut payload = Vec::new();
+        for value in [1.0f32, 4.0, 2.0, 5.0, 3.0, 6.0] {
+            payload.extend_from_slice(&value.to_le_bytes());
+        }
+        let header = "{'descr': '<f4', 'fortran_order': True, 'shape': (2, 3), }\n";
+        let m = Matrix::<2, 3, f32>::from_npy_bytes(&npy_with_header(header, &payload)).unwrap();
+        assert_eq!(m.data, [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let header = "{'descr': '<f4', 'fortran_order': False, 'shape': (6,), }\n";
+        let bytes = npy_with_header(header, &payload);
+        assert_eq!(Matrix::<1, 6, f32>::from_npy_bytes(&bytes).unwrap().data, [[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]]);
+        assert_eq!(Matrix::<6, 1, f32>::from_npy_bytes(&bytes).unwrap().data[1], [4.0]);
+        assert!(Matrix::<2, 3, f32>::from_npy_bytes(&bytes).is_err());
+        assert_eq!(
+            Matrix::<6, 1, f32>::from_npy_bytes(&bytes[..bytes.len() - 1]).unwrap_err(),
+            OperationError::Truncated {
+                expected: 24,
+                found: 23
+            }
+        );
+    }
+
+    #[test]
+    fn test_file_round_trip() {
+        let dir = std::env::temp_dir();
+        let id = std::process::id();
+        let m = Matrix::<2, 2, f64>::new([[1.0, 2.0], [3.0, 4.0]]);
+
+        let csv = dir.join(format!("zmatrix_io_{}.csv", id));
+        m.write_csv(&csv).unwrap();
+        assert_eq!(Matrix::<2, 2, f64>::read_csv(&csv).unwrap().data, m.data);
+        let mtx = dir.join(format!("zmatrix_io_{}.mtx", id));
+        m.write_matrix_market(&mtx).unwrap();
+        assert_eq!(Matrix::<2, 2, f64>::read_matrix_market(&mtx).unwrap().data, m.data);
+        let npy = dir.join(format!("zmatrix_io_{}.npy", id));
+        m.write_npy(&npy).unwrap();
+        assert_eq!(Matrix::<2, 2, f64>::read_npy(&npy).unwrap().data, m.data);
+        for path in [csv, mtx, npy] {
+            fs::remove_file(path).unwrap();
+        }
+
+        assert!(Matrix::<2, 2, f64>::read_csv(dir.join("zmatrix_io_missing.csv")).is_err());
+    }
+}
@@ -0,0 +1,177 @@
+use crate::dense::{util, Matrix};
+use array_init::array_init;
+use rayon::prelude::*;
+use std::ops::{Index, IndexMut};
+
+// 按 (行, 列) 下标访问，越界时 panic；需要返回错误时使用 get/set
+impl<const ROWS: usize, const COLS: usize, T> Index<(usize, usize)> for Matrix<ROWS, COLS, T>
+where
+    T: Copy + Send + Sync,
+{
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        &self.data[row][col]
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, T> IndexMut<(usize, usize)> for Matrix<ROWS, COLS, T>
+where
+    T: Copy + Send + Sync,
+{
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        &mut self.data[row][col]
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, T> Matrix<ROWS, COLS, T>
+where
+    T: Copy + Send + Sync,
+{
+    // 按行优先顺序遍历所有元素
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter().flat_map(|row| row.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.data.iter_mut().flat_map(|row| row.iter_mut())
+    }
+
+    // 逐行遍历
+    pub fn rows(&self) -> impl Iterator<Item = &[T; COLS]> {
+        self.data.iter()
+    }
+
+    // 对每个元素做变换，生成同尺寸的新矩阵
+    pub fn map<U, F>(&self, mut func: F) -> Matrix<ROWS, COLS, U>
+    where
+        U: Copy + Send + Sync,
+        F: FnMut(T) -> U,
+    {
+        let data = array_init(|row| array_init(|col| func(self.data[row][col])));
+        Matrix { data, digits: 0 }
+    }
+
+    // 两个同尺寸矩阵逐元素组合
+    pub fn zip_map<U, V, F>(&self, other: &Matrix<ROWS, COLS, U>, mut func: F) -> Matrix<ROWS, COLS, V>
+    where
+        U: Copy + Send + Sync,
+        V: Copy + Send + Sync,
+        F: FnMut(T, U) -> V,
+    {
+        let data = array_init(|row| array_init(|col| func(self.data[row][col], other.data[row][col])));
+        Matrix { data, digits: 0 }
+    }
+
+    // 使用 rayon 按行并行的 map，元素较少时退化为串行
+    pub fn par_map<U, F>(&self, func: F) -> Matrix<ROWS, COLS, U>
+    where
+        U: Copy + Send + Sync + Default,
+        F: Fn(T) -> U + Send + Sync,
+    {
+        if ROWS * COLS < util::PARALLEL_ELEMENT_THRESHOLD {
+            return self.map(func);
+        }
+        let mut data = [[U::default(); COLS]; ROWS];
+        data.par_iter_mut().zip(self.data.par_iter()).for_each(|(out, row)| {
+            for (cell, &value) in out.iter_mut().zip(row.iter()) {
+                *cell = func(value);
+            }
+        });
+        Matrix { data, digits: 0 }
+    }
+
+    // 使用 rayon 按行并行的 zip_map，元素较少时退化为串行
+    pub fn par_zip_map<U, V, F>(&self, other: &Matrix<ROWS, COLS, U>, func: F) -> Matrix<ROWS, COLS, V>
+    where
+        U: Copy + Send + Sync,
+        V: Copy + Send + Sync + Default,
+        F: Fn(T, U) -> V + Send + Sync,
+    {
+        if ROWS * COLS < util::PARALLEL_ELEMENT_THRESHOLD {
+            return self.zip_map(other, func);
+        }
+        let mut data = [[V::default(); COLS]; ROWS];
+        data.par_iter_mut()
+            .zip(self.data.par_iter().zip(other.data.par_iter()))
+            .for_each(|(out, (a, b))| {
+                for col in 0..COLS {
+                    out[col] = func(a[col], b[col]);
+                }
+            });
+        Matrix { data, digits: 0 }
+    }
+
+    // 按行优先顺序累积所有元素
+    pub fn fold<B, F>(&self, init: B, func: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        self.iter().copied().fold(init, func)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index() {
+        let mut m = Matrix::<2, 3, i32>::new([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(m[(0, 2)], 3);
+        assert_eq!(m[(1, 0)], 4);
+        m[(1, 0)] = 10;
+        assert_eq!(m.data, [[1, 2, 3], [10, 5, 6]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let m = Matrix::<2, 3, i32>::new([[1, 2, 3], [4, 5, 6]]);
+        let _ = m[(2, 0)];
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut m = Matrix::<2, 2, i32>::new([[1, 2], [3, 4]]);
+        assert_eq!(m.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        for value in m.iter_mut() {
+            *value *= 2;
+        }
+        assert_eq!(m.data, [[2, 4], [6, 8]]);
+        let rows: Vec<_> = m.rows().collect();
+        assert_eq!(rows, vec![&[2, 4], &[6, 8]]);
+    }
+
+    #[test]
+    fn test_map_and_zip_map() {
+        let m = Matrix::<2, 2, i32>::new([[1, 2], [3, 4]]);
+        let squared = m.map(|x| (x * x) as f64);
+        assert_eq!(squared.data, [[1.0, 4.0], [9.0, 16.0]]);
+        let sum = m.zip_map(&squared, |a, b| a as f64 + b);
+        assert_eq!(sum.data, [[2.0, 6.0], [12.0, 20.0]]);
+    }
+
+    #[test]
+    fn test_par_map_and_par_zip_map() {
+        let small = Matrix::<2, 2, i32>::new([[1, 2], [3, 4]]);
+        assert_eq!(small.par_map(|x| x * 2).data, [[2, 4], [6, 8]]);
+        assert_eq!(small.par_zip_map(&small, |a, b| a * b).data, [[1, 4], [9, 16]]);
+
+        let mut large = Matrix::<150, 200, f64>::zeros();
+        for (i, value) in large.iter_mut().enumerate() {
+            *value = i as f64;
+        }
+        let doubled = large.par_map(|x| x * 2.0);
+        let sum = large.par_zip_map(&doubled, |a, b| a + b);
+        assert_eq!(doubled.data[149][199], 2.0 * (150 * 200 - 1) as f64);
+        assert_eq!(sum.data[100][3], 3.0 * (100 * 200 + 3) as f64);
+    }
+
+    #[test]
+    fn test_fold() {
+        let m = Matrix::<2, 3, i32>::new([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(m.fold(0, |acc, x| acc + x), 21);
+        assert_eq!(m.fold(i32::MIN, |acc, x| acc.max(x)), 6);
+    }
+}
@@ -0,0 +1,326 @@
+use crate::constant::get_flt64_zero;
+use crate::dense::error::OperationError;
+use crate::dense::Matrix;
+
+// Hager 估计的最大迭代次数，与 LAPACK 的 xLACON 相同
+const HAGER_MAX_ITERATIONS: usize = 5;
+
+// 带部分主元的 LU 分解结果：P·A = L·U
+#[derive(Clone, Copy, Debug)]
+pub struct LUDecomposition<const N: usize> {
+    pub l: Matrix<N, N, f64>,
+    pub u: Matrix<N, N, f64>,
+    // perm[i] 表示 P·A 的第 i 行来自 A 的第 perm[i] 行
+    pub perm: [usize; N],
+    // 行交换次数为偶数时为 1.0，奇数时为 -1.0
+    pub sign: f64,
+}
+
+impl<const N: usize> LUDecomposition<N> {
+    // 由行排列生成置换矩阵 P
+    pub fn permutation_matrix(&self) -> Matrix<N, N, f64> {
+        let mut data = [[0.0; N]; N];
+        for (row, &src) in self.perm.iter().enumerate() {
+            data[row][src] = 1.0;
+        }
+        Matrix::new(data)
+    }
+
+    // 求解 A·x = b，先前代 L·y = P·b，再回代 U·x = y
+    pub fn solve_vector(&self, b: &[f64; N]) -> [f64; N] {
+        let mut x = [0.0; N];
+        for i in 0..N {
+            let mut sum = b[self.perm[i]];
+            for j in 0..i {
+                sum -= self.l.data[i][j] * x[j];
+            }
+            x[i] = sum;
+        }
+        for i in (0..N).rev() {
+            let mut sum = x[i];
+            for j in (i + 1)..N {
+                sum -= self.u.data[i][j] * x[j];
+            }
+            x[i] = sum / self.u.data[i][i];
+        }
+        x
+    }
+
+    // 求解 Aᵀ·x = b：Aᵀ = Uᵀ·Lᵀ·P，依次解 Uᵀ·z = b、Lᵀ·w = z，再按行排列还原 x
+    pub fn solve_transpose_vector(&self, b: &[f64; N]) -> [f64; N] {
+        let mut z = [0.0; N];
+        for i in 0..N {
+            let mut sum = b[i];
+            for j in 0..i {
+                sum -= self.u.data[j][i] * z[j];
+            }
+            z[i] = sum / self.u.data[i][i];
+        }
+        for i in (0..N).rev() {
+            for j in (i + 1)..N {
+                z[i] -= self.l.data[j][i] * z[j];
+            }
+        }
+        let mut x = [0.0; N];
+        for (i, &src) in self.perm.iter().enumerate() {
+            x[src] = z[i];
+        }
+        x
+    }
+
+    // ‖A⁻¹‖₁ 的估计值（Hager 算法加 Higham 的交替符号向量），只做 O(n²) 的三角求解，不构造逆矩阵
+    pub fn inverse_norm_1_estimate(&self) -> f64 {
+        if N == 0 {
+            return 0.0;
+        }
+        let mut x = [1.0 / N as f64; N];
+        let mut estimate = 0.0;
+        for _ in 0..HAGER_MAX_ITERATIONS {
+            let y = self.solve_vector(&x);
+            estimate = y.iter().map(|v| v.abs()).sum::<f64>();
+            let mut signs = [0.0; N];
+            for (s, v) in signs.iter_mut().zip(y.iter()) {
+                *s = if *v >= 0.0 { 1.0 } else { -1.0 };
+            }
+            let z = self.solve_transpose_vector(&signs);
+            let mut j = 0;
+            for i in 1..N {
+                if z[i].abs() > z[j].abs() {
+                    j = i;
+                }
+            }
+            let zx: f64 = z.iter().zip(x.iter()).map(|(a, b)| a * b).sum();
+            if z[j].abs() <= zx {
+                break;
+            }
+            x = [0.0; N];
+            x[j] = 1.0;
+        }
+        let mut alt = [0.0; N];
+        for (i, v) in alt.iter_mut().enumerate() {
+            let sign = if i % 2 == 0 { 1.0 } else { -1.0 };
+            *v = sign * (1.0 + i as f64 / (N.max(2) - 1) as f64);
+        }
+        let alt_estimate = 2.0 * self.solve_vector(&alt).iter().map(|v| v.abs()).sum::<f64>()
+            / (3 * N) as f64;
+        estimate.max(alt_estimate)
+    }
+
+    // 逐列求解 A·x = eᵢ 得到逆矩阵
+    pub fn inverse(&self) -> Matrix<N, N, f64> {
+        let mut data = [[0.0; N]; N];
+        for col in 0..N {
+            let mut e = [0.0; N];
+            e[col] = 1.0;
+            let x = self.solve_vector(&e);
+            for row in 0..N {
+                data[row][col] = x[row];
+            }
+        }
+        Matrix::new(data)
+    }
+
+    pub fn det(&self) -> f64 {
+        let mut det = self.sign;
+        for i in 0..N {
+            det *= self.u.data[i][i];
+        }
+        det
+    }
+}
+
+impl<const N: usize> Matrix<N, N, f64> {
+    // 部分主元 LU 分解，主元绝对值不大于 get_flt64_zero() 乘以原矩阵该列最大绝对值时视为奇异矩阵，
+    // 因此整体缩放（例如 1e-15·I）不会改变判断结果
+    pub fn lu(&self) -> Result<LUDecomposition<N>, OperationError> {
+        let mut a = self.data;
+        let mut scale = [0.0f64; N];
+        for row in a.iter() {
+            for (s, v) in scale.iter_mut().zip(row.iter()) {
+                *s = s.max(v.abs());
+            }
+        }
+        let mut l = [[0.0; N]; N];
+        let mut perm = [0usize; N];
+        for (i, p) in perm.iter_mut().enumerate() {
+            *p = i;
+        }
+        let mut sign = 1.0;
+        for k in 0..N {
+            let mut pivot_row = k;
+            let mut pivot_abs = a[k][k].abs();
+            for row in (k + 1)..N {
+                if a[row][k].abs() > pivot_abs {
+                    pivot_abs = a[row][k].abs();
+                    pivot_row = row;
+                }
+            }
+            if pivot_abs <= get_flt64_zero() * scale[k] || pivot_abs.is_nan() {
+                return Err(OperationError::Singular {
+                    condition: f64::INFINITY,
+                });
+            }
+            if pivot_row != k {
+                a.swap(k, pivot_row);
+                l.swap(k, pivot_row);
+                perm.swap(k, pivot_row);
+                sign = -sign;
+            }
+            l[k][k] = 1.0;
+            for row in (k + 1)..N {
+                let factor = a[row][k] / a[k][k];
+                l[row][k] = factor;
+                a[row][k] = 0.0;
+                for col in (k + 1)..N {
+                    a[row][col] -= factor * a[k][col];
+                }
+            }
+        }
+        Ok(LUDecomposition {
+            l: Matrix::new(l),
+            u: Matrix::new(a),
+            perm,
+            sign,
+        })
+    }
+}
+
+impl<const N: usize> Matrix<N, N, f32> {
+    pub fn lu(&self) -> Result<LUDecomposition<N>, OperationError> {
+        let mut data = [[0.0; N]; N];
+        for row in 0..N {
+            for col in 0..N {
+                data[row][col] = self.data[row][col] as f64;
+            }
+        }
+        Matrix::<N, N, f64>::new(data).lu()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn assert_matrix_eq<const N: usize>(a: &Matrix<N, N, f64>, b: &Matrix<N, N, f64>) {
+        for row in 0..N {
+            for col in 0..N {
+                assert_relative_eq!(a.data[row][col], b.data[row][col], epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lu_reconstructs_matrix() {
+        let m = Matrix::<3, 3, f64>::new([[2.0, 1.0, 1.0], [4.0, -6.0, 0.0], [-2.0, 7.0, 2.0]]);
+        let lu = m.lu().unwrap();
+        let pa = lu.permutation_matrix().product(&m).unwrap();
+        let product = lu.l.product(&lu.u).unwrap();
+        assert_matrix_eq(&pa, &product);
+        for row in 0..3 {
+            assert_relative_eq!(lu.l.data[row][row], 1.0);
+            for col in (row + 1)..3 {
+                assert_relative_eq!(lu.l.data[row][col], 0.0);
+                assert_relative_eq!(lu.u.data[col][row], 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lu_pivoting() {
+        let m = Matrix::<2, 2, f64>::new([[0.0, 1.0], [1.0, 0.0]]);
+        let lu = m.lu().unwrap();
+        assert_eq!(lu.perm, [1, 0]);
+        assert_relative_eq!(lu.sign, -1.0);
+        assert_relative_eq!(lu.det(), -1.0);
+    }
+
+    #[test]
+    fn test_lu_solve_vector() {
+        let m = Matrix::<3, 3, f64>::new([[2.0, 1.0, 1.0], [4.0, -6.0, 0.0], [-2.0, 7.0, 2.0]]);
+        let x = m.lu().unwrap().solve_vector(&[5.0, -2.0, 9.0]);
+        assert_relative_eq!(x[0], 1.0, epsilon = 1e-10);
+        assert_relative_eq!(x[1], 1.0, epsilon = 1e-10);
+        assert_relative_eq!(x[2], 2.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_lu_solve_transpose_vector() {
+        let m = Matrix::<3, 3, f64>::new([[2.0, 1.0, 1.0], [4.0, -6.0, 0.0], [-2.0, 7.0, 2.0]]);
+        let x = m.lu().unwrap().solve_transpose_vector(&[1.0, 2.0, 3.0]);
+        let t = m.T();
+        for row in 0..3 {
+            let sum: f64 = (0..3).map(|col| t.data[row][col] * x[col]).sum();
+            assert_relative_eq!(sum, (row + 1) as f64, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_inverse_norm_1_estimate() {
+        let matrices = [
+            Matrix::<3, 3, f64>::new([[2.0, 1.0, 1.0], [4.0, -6.0, 0.0], [-2.0, 7.0, 2.0]]),
+            Matrix::<3, 3, f64>::new([[4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]]),
+            Matrix::<3, 3, f64>::new([[1.0, 1.0, 1.0], [1.0, 1.0 + 1e-8, 1.0], [0.0, 1.0, 3.0]]),
+        ];
+        for m in matrices.iter() {
+            let lu = m.lu().unwrap();
+            let exact = Matrix::<3, 3, f64>::norm_1_of(&lu.inverse().data);
+            // 估计值是下界，小矩阵上通常精确
+            assert_relative_eq!(lu.inverse_norm_1_estimate(), exact, max_relative = 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_lu_singular() {
+        let m = Matrix::<3, 3, f64>::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        let err = m.lu().unwrap_err();
+        assert!(matches!(err, OperationError::Singular { .. }));
+    }
+
+    #[test]
+    fn test_lu_scale_invariant() {
+        let tiny = Matrix::<3, 3, f64>::unit().scale(1e-15);
+        let lu = tiny.lu().unwrap();
+        assert_relative_eq!(lu.det(), 1e-45, max_relative = 1e-12);
+
+        // 各列量级不同也不影响奇异性判断
+        let mixed = Matrix::<2, 2, f64>::new([[1e6, 0.0], [0.0, 1e-10]]);
+        assert_relative_eq!(mixed.lu().unwrap().det(), 1e-4, max_relative = 1e-12);
+
+        let zero_column = Matrix::<2, 2, f64>::new([[1.0, 0.0], [2.0, 0.0]]);
+        assert!(matches!(
+            zero_column.lu(),
+            Err(OperationError::Singular { .. })
+        ));
+    }
+
+    #[test]
+    fn test_lu_f32() {
+        let m = Matrix::<2, 2, f32>::new([[1.0, 2.0], [3.0, 4.0]]);
+        assert_relative_eq!(m.lu().unwrap().det(), -2.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_det_float_uses_lu() {
+        let m = Matrix::<3, 3, f64>::new([[2.0, 1.0, 1.0], [4.0, -6.0, 0.0], [-2.0, 7.0, 2.0]]);
+        assert_relative_eq!(m.det().unwrap(), -16.0, epsilon = 1e-10);
+
+        let singular = Matrix::<3, 3, f64>::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        assert_relative_eq!(singular.det().unwrap(), 0.0);
+
+        assert_relative_eq!(
+            Matrix::<2, 2, f64>::unit().scale(1e-15).det().unwrap(),
+            1e-30,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            Matrix::<2, 2, f32>::unit().scale(1e-3).det().unwrap(),
+            1e-6,
+            max_relative = 1e-5
+        );
+
+        // 置换法在此规模下不可用
+        let big = Matrix::<20, 20, f64>::unit().scale(2.0);
+        assert_relative_eq!(big.det().unwrap(), 2f64.powi(20), epsilon = 1e-6);
+    }
+}
@@ -26,13 +26,7 @@ where
     type Output = Matrix<ROWS, COLS, T>;
 
     fn add(self, other: Self) -> Self::Output {
-        let mut data = [[T::default(); COLS]; ROWS];
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                data[row][col] = self.data[row][col] + other.data[row][col];
-            }
-        }
-        Matrix { data, digits: 0 }
+        self.zip_map(&other, |a, b| a + b)
     }
 }
 
@@ -42,13 +36,7 @@ where
 {
     type Output = Matrix<ROWS, COLS, T>;
     fn sub(self, other: Self) -> Self::Output {
-        let mut data = [[T::default(); COLS]; ROWS];
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                data[row][col] = self.data[row][col] - other.data[row][col];
-            }
-        }
-        Matrix { data, digits: 0 }
+        self.zip_map(&other, |a, b| a - b)
     }
 }
 
@@ -59,13 +47,7 @@ where
     type Output = Matrix<ROWS, COLS, T>;
 
     fn mul(self, other: Self) -> Self::Output {
-        let mut data = [[T::default(); COLS]; ROWS];
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                data[row][col] = self.data[row][col] * other.data[row][col];
-            }
-        }
-        Matrix { data, digits: 0 }
+        self.zip_map(&other, |a, b| a * b)
     }
 }
 
@@ -76,13 +58,7 @@ where
     type Output = Matrix<ROWS, COLS, T>;
 
     fn neg(self) -> Self::Output {
-        let mut data = [[T::default(); COLS]; ROWS];
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                data[row][col] = -self.data[row][col];
-            }
-        }
-        Matrix { data, digits: 0 }
+        self.map(|a| -a)
     }
 }
 
@@ -90,14 +66,14 @@ impl<const ROWS: usize, const COLS: usize, T> Matrix<ROWS, COLS, T>
 where
     T: Copy + Send + Sync + Into<f64> + Display,
 {
+    // 逐元素求 e^x，矩阵指数请使用 expm
+    pub fn exp_elementwise(&self) -> Matrix<ROWS, COLS, f64> {
+        self.map(|a| Into::<f64>::into(a).exp())
+    }
+
+    #[deprecated(note = "elementwise exponential, use exp_elementwise (or expm for the matrix exponential)")]
     pub fn exp(&self) -> Matrix<ROWS, COLS, f64> {
-        let mut data = [[0.0; COLS]; ROWS];
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                data[row][col] = self.data[row][col].into().exp();
-            }
-        }
-        Matrix { data, digits: 0 }
+        self.exp_elementwise()
     }
 }
 
@@ -108,13 +84,7 @@ where
     type Output = Matrix<ROWS, COLS, T>;
 
     fn add(self, rhs: T) -> Self::Output {
-        let mut data = [[T::default(); COLS]; ROWS];
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                data[row][col] = self.data[row][col] + rhs;
-            }
-        }
-        Matrix { data, digits: 0 }
+        self.map(|a| a + rhs)
     }
 }
 
@@ -124,13 +94,7 @@ where
 {
     type Output = Matrix<ROWS, COLS, T>;
     fn sub(self, rhs: T) -> Self::Output {
-        let mut data = [[T::default(); COLS]; ROWS];
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                data[row][col] = self.data[row][col] - rhs;
-            }
-        }
-        Matrix { data, digits: 0 }
+        self.map(|a| a - rhs)
     }
 }
 
@@ -141,13 +105,7 @@ where
     type Output = Matrix<ROWS, COLS, T>;
 
     fn mul(self, rhs: T) -> Self::Output {
-        let mut data = [[T::default(); COLS]; ROWS];
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                data[row][col] = self.data[row][col] * rhs;
-            }
-        }
-        Matrix { data, digits: 0 }
+        self.map(|a| a * rhs)
     }
 }
 
@@ -157,13 +115,7 @@ where
 {
     type Output = Matrix<ROWS, COLS, T>;
     fn div(self, rhs: T) -> Self::Output {
-        let mut data = [[T::default(); COLS]; ROWS];
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                data[row][col] = self.data[row][col] / rhs;
-            }
-        }
-        Matrix { data, digits: 0 }
+        self.map(|a| a / rhs)
     }
 }
 impl<const ROWS: usize, const COLS: usize, T> Sub<Matrix<ROWS, COLS, T>> for Scalar<T>
@@ -173,13 +125,7 @@ where
     type Output = Matrix<ROWS, COLS, T>;
 
     fn sub(self, rhs: Matrix<ROWS, COLS, T>) -> Self::Output {
-        let mut data = [[T::default(); COLS]; ROWS];
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                data[row][col] = self.value - rhs.data[row][col];
-            }
-        }
-        Matrix { data, digits: 0 }
+        rhs.map(|a| self.value - a)
     }
 }
 
@@ -192,15 +138,25 @@ where
         target: &Matrix<COLS, COLS2, T>,
     ) -> Result<Matrix<ROWS, COLS2, T>, error::OperationError> {
         let mut data = [[T::default(); COLS2]; ROWS];
-        for i in 0..ROWS {
-            for j in 0..COLS2 {
-                let mut sum = T::default();
-                for k in 0..COLS {
-                    sum = sum + self.data[i][k] * target.data[k][j];
-                }
-                data[i][j] = sum;
-            }
+        util::product_rows(&self.data, &target.data, &mut data);
+        Ok(Matrix { data, digits: 0 })
+    }
+
+    // 使用 rayon 按行分块并行的矩阵乘法，运算量较小时退化为串行
+    pub fn par_product<const COLS2: usize>(
+        &self,
+        target: &Matrix<COLS, COLS2, T>,
+    ) -> Result<Matrix<ROWS, COLS2, T>, error::OperationError> {
+        if ROWS * COLS * COLS2 < util::PARALLEL_THRESHOLD {
+            return self.product(target);
         }
+        let mut data = [[T::default(); COLS2]; ROWS];
+        data.par_chunks_mut(util::PARALLEL_ROW_CHUNK)
+            .enumerate()
+            .for_each(|(i, out)| {
+                let start = i * util::PARALLEL_ROW_CHUNK;
+                util::product_rows(&self.data[start..start + out.len()], &target.data, out);
+            });
         Ok(Matrix { data, digits: 0 })
     }
 }
@@ -210,13 +166,7 @@ where
     T: Display + Send + Sync + Mul<Output = T> + Copy + Default,
 {
     pub fn scale(&self, scalar: T) -> Matrix<ROWS, COLS, T> {
-        let mut data = [[T::default(); COLS]; ROWS];
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                data[row][col] = self.data[row][col] * scalar;
-            }
-        }
-        Matrix { data, digits: 0 }
+        self.map(|a| a * scalar)
     }
 }
 
@@ -227,13 +177,7 @@ where
     type Output = Matrix<ROWS, COLS, T>;
 
     fn div(self, rhs: Matrix<ROWS, COLS, T>) -> Self::Output {
-        let mut data = [[T::default(); COLS]; ROWS];
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                data[row][col] = self.value / rhs.data[row][col];
-            }
-        }
-        Matrix { data, digits: 0 }
+        rhs.map(|a| self.value / a)
     }
 }
 
@@ -241,26 +185,20 @@ impl<const ROWS: usize, const COLS: usize, T> Matrix<ROWS, COLS, T>
 where
     T: Copy + Send + Sync + Display + Into<f64>,
 {
+    // 逐元素求 ln(x)，矩阵对数请使用 logm
+    pub fn ln_elementwise(&self) -> Matrix<ROWS, COLS, f64> {
+        self.map(|a| Into::<f64>::into(a).ln())
+    }
+
+    #[deprecated(note = "elementwise logarithm, use ln_elementwise (or logm for the matrix logarithm)")]
     pub fn ln(&self) -> Matrix<ROWS, COLS, f64> {
-        let mut data = [[0.0; COLS]; ROWS];
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                data[row][col] = self.data[row][col].into().ln();
-            }
-        }
-        Matrix { data, digits: 0 }
+        self.ln_elementwise()
     }
 
     pub fn clamp(&self, min: T, max: T) -> Matrix<ROWS, COLS, f64> {
         let min_f: f64 = min.into();
         let max_f: f64 = max.into();
-        let mut data = [[0.0; COLS]; ROWS];
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                data[row][col] = self.data[row][col].into().clamp(min_f, max_f);
-            }
-        }
-        Matrix { data, digits: 0 }
+        self.map(|a| Into::<f64>::into(a).clamp(min_f, max_f))
     }
 }
 
@@ -272,13 +210,7 @@ where
         if ROWS == 0 || COLS == 0 {
             return T::default();
         }
-        let mut sum = T::default();
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                sum = sum + self.data[row][col];
-            }
-        }
-        sum
+        self.fold(T::default(), |sum, value| sum + value)
     }
 }
 
@@ -337,6 +269,43 @@ mod test {
         assert_eq!(result.unwrap().data, [[38, 44, 50, 56], [83, 98, 113, 128]]);
     }
 
+    #[test]
+    fn test_matrix_product_blocked() {
+        // 尺寸跨越多个分块且不是分块大小的整数倍
+        let mut a = Matrix::<70, 130, i64>::new([[0; 130]; 70]);
+        let mut b = Matrix::<130, 67, i64>::new([[0; 67]; 130]);
+        for row in 0..70 {
+            for col in 0..130 {
+                a.data[row][col] = ((row * 7 + col * 3) % 11) as i64 - 5;
+            }
+        }
+        for row in 0..130 {
+            for col in 0..67 {
+                b.data[row][col] = ((row * 5 + col) % 13) as i64 - 6;
+            }
+        }
+        let result = a.product(&b).unwrap();
+        let parallel = a.par_product(&b).unwrap();
+        for i in 0..70 {
+            for j in 0..67 {
+                let mut expected = 0;
+                for k in 0..130 {
+                    expected += a.data[i][k] * b.data[k][j];
+                }
+                assert_eq!(result.data[i][j], expected);
+                assert_eq!(parallel.data[i][j], expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_par_product_small() {
+        let m1 = Matrix::<2, 3, i32>::new([[1, 2, 3], [4, 5, 6]]);
+        let m2 = Matrix::<3, 4, i32>::new([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+        let result = m1.par_product(&m2).unwrap();
+        assert_eq!(result.data, [[38, 44, 50, 56], [83, 98, 113, 128]]);
+    }
+
     #[test]
     fn test_neg() {
         let m1 = Matrix::<2, 3, i32>::new([[1, 2, 3], [4, 5, 6]]);
@@ -349,7 +318,7 @@ mod test {
     #[test]
     fn test_exp_positive_values() {
         let matrix = Matrix::<2, 2, f64>::new([[1.0, 2.0], [0.5, 3.0]]);
-        let result = matrix.exp();
+        let result = matrix.exp_elementwise();
 
         assert_eq!(result.height(), 2);
         assert_eq!(result.width(), 2);
@@ -378,7 +347,7 @@ mod test {
     #[test]
     fn test_exp_large_values() {
         let matrix = Matrix::<1, 2, f64>::new([[10.0, 20.0]]);
-        let result = matrix.exp();
+        let result = matrix.exp_elementwise();
 
         assert_eq!(result.height(), 1);
         assert_eq!(result.width(), 2);
@@ -389,7 +358,7 @@ mod test {
     #[test]
     fn test_exp_small_values() {
         let matrix = Matrix::<1, 2, f64>::new([[1e-10, -1e-10]]);
-        let result = matrix.exp();
+        let result = matrix.exp_elementwise();
 
         assert_eq!(result.height(), 1);
         assert_eq!(result.width(), 2);
@@ -400,7 +369,7 @@ mod test {
     #[test]
     fn test_exp_single_element_matrix() {
         let matrix = Matrix::<1, 1, f64>::new([[2.0]]);
-        let result = matrix.exp();
+        let result = matrix.exp_elementwise();
 
         assert_eq!(result.height(), 1);
         assert_eq!(result.width(), 1);
@@ -410,7 +379,7 @@ mod test {
     #[test]
     fn test_exp_preserves_dimensions() {
         let matrix = Matrix::<3, 4, f64>::new([[1.0; 4]; 3]);
-        let result = matrix.exp();
+        let result = matrix.exp_elementwise();
 
         assert_eq!(result.height(), 3);
         assert_eq!(result.width(), 4);
@@ -421,7 +390,7 @@ mod test {
     #[test]
     fn test_exp_with_nan() {
         let matrix = Matrix::<1, 1, f64>::new([[f64::NAN]]);
-        let result = matrix.exp();
+        let result = matrix.exp_elementwise();
 
         assert!(result.data[0][0].is_nan());
     }
@@ -429,7 +398,7 @@ mod test {
     #[test]
     fn test_exp_with_infinity() {
         let matrix = Matrix::<1, 2, f64>::new([[f64::INFINITY, f64::NEG_INFINITY]]);
-        let result = matrix.exp();
+        let result = matrix.exp_elementwise();
 
         assert_eq!(result.data[0][0], f64::INFINITY);
         assert_eq!(result.data[0][1], 0.0);
@@ -573,7 +542,7 @@ mod test {
     #[test]
     fn test_log_positive_values() {
         let matrix = Matrix::<2, 2, f64>::new([[1.0, 2.0], [3.0, 4.0]]);
-        let result = matrix.ln();
+        let result = matrix.ln_elementwise();
 
         assert_eq!(result.height(), 2);
         assert_eq!(result.width(), 2);
@@ -586,7 +555,7 @@ mod test {
     #[test]
     fn test_log_fractional_values() {
         let matrix = Matrix::<1, 3, f64>::new([[0.5, 0.25, 0.125]]);
-        let result = matrix.ln();
+        let result = matrix.ln_elementwise();
 
         assert_eq!(result.height(), 1);
         assert_eq!(result.width(), 3);
@@ -598,7 +567,7 @@ mod test {
     #[test]
     fn test_log_zero_values() {
         let matrix = Matrix::<2, 2, f64>::new([[0.0, 0.0], [0.0, 0.0]]);
-        let r = matrix.ln();
+        let r = matrix.ln_elementwise();
         assert!(r.data[0][0].is_infinite());
         assert!(r.data[0][1].is_infinite());
         assert!(r.data[1][0].is_infinite());
@@ -608,7 +577,7 @@ mod test {
     #[test]
     fn test_log_negative_values() {
         let matrix = Matrix::<1, 2, f64>::new([[-1.0, -2.0]]);
-        let r = matrix.ln();
+        let r = matrix.ln_elementwise();
         assert!(r.data[0][0].is_nan());
         assert!(r.data[0][1].is_nan());
     }
@@ -616,7 +585,7 @@ mod test {
     #[test]
     fn test_log_preserves_dimensions() {
         let matrix = Matrix::<3, 4, f64>::new([[1.1; 4]; 3]);
-        let result = matrix.ln();
+        let result = matrix.ln_elementwise();
 
         assert_eq!(result.height(), 3);
         assert_eq!(result.width(), 4);
@@ -626,7 +595,7 @@ mod test {
     #[test]
     fn test_log_single_element_matrix() {
         let matrix = Matrix::<1, 1, f64>::new([[2.718281828459045]]);
-        let result = matrix.ln();
+        let result = matrix.ln_elementwise();
 
         assert_eq!(result.height(), 1);
         assert_eq!(result.width(), 1);
@@ -636,14 +605,14 @@ mod test {
     #[test]
     fn test_log_with_nan() {
         let matrix = Matrix::<1, 1, f64>::new([[f64::NAN]]);
-        let r = matrix.ln();
+        let r = matrix.ln_elementwise();
         assert!(r.data[0][0].is_nan());
     }
 
     #[test]
     fn test_log_with_infinity() {
         let matrix = Matrix::<1, 2, f64>::new([[f64::INFINITY, f64::NEG_INFINITY]]);
-        let r = matrix.ln();
+        let r = matrix.ln_elementwise();
         assert!(r.data[0][0].is_infinite());
         assert!(r.data[0][1].is_nan());
     }
@@ -651,7 +620,7 @@ mod test {
     #[test]
     fn test_log_large_values() {
         let matrix = Matrix::<1, 2, f64>::new([[1e50, 1e100]]);
-        let result = matrix.ln();
+        let result = matrix.ln_elementwise();
 
         assert_relative_eq!(
             result.data[0][0],
@@ -668,7 +637,7 @@ mod test {
     #[test]
     fn test_log_small_positive_values() {
         let matrix = Matrix::<1, 2, f64>::new([[1e-50, 1e-100]]);
-        let result = matrix.ln();
+        let result = matrix.ln_elementwise();
 
         assert_relative_eq!(
             result.data[0][0],
@@ -685,7 +654,7 @@ mod test {
     #[test]
     fn test_log_edge_case_near_one() {
         let matrix = Matrix::<1, 2, f64>::new([[1.0 - 1e-10, 1.0 + 1e-10]]);
-        let result = matrix.ln();
+        let result = matrix.ln_elementwise();
 
         // Test values very close to 1.0
         assert_relative_eq!(result.data[0][0], (1.0 - 1e-10f64).ln(), epsilon = 1e-6); // More tolerant epsilon
@@ -0,0 +1,194 @@
+use crate::constant::get_flt64_zero;
+use crate::dense::error::OperationError;
+use crate::dense::Matrix;
+
+// Householder QR 分解结果：A = Q·R
+#[derive(Clone, Copy, Debug)]
+pub struct QRDecomposition<const ROWS: usize, const COLS: usize> {
+    // ROWS×ROWS 正交矩阵
+    pub q: Matrix<ROWS, ROWS, f64>,
+    // ROWS×COLS 上三角矩阵
+    pub r: Matrix<ROWS, COLS, f64>,
+}
+
+impl<const ROWS: usize, const COLS: usize> QRDecomposition<ROWS, COLS> {
+    // 最小二乘求解 min‖A·X − B‖，要求 ROWS ≥ COLS 且 A 列满秩
+    pub fn solve<const K: usize>(
+        &self,
+        b: &Matrix<ROWS, K, f64>,
+    ) -> Result<Matrix<COLS, K, f64>, OperationError> {
+        // 至少需要 COLS 行
+        if ROWS < COLS {
+            return Err(OperationError::DimensionMismatch {
+                expected: (COLS, COLS),
+                found: (ROWS, COLS),
+            });
+        }
+        let r = &self.r.data;
+        let mut scale: f64 = 0.0;
+        for i in 0..COLS {
+            scale = scale.max(r[i][i].abs());
+        }
+        for i in 0..COLS {
+            if r[i][i].abs() <= get_flt64_zero() * scale || r[i][i] == 0.0 {
+                return Err(OperationError::RankDeficient);
+            }
+        }
+        let qtb = self.q.T().product(b)?;
+        let mut data = [[0.0; K]; COLS];
+        for col in 0..K {
+            for i in (0..COLS).rev() {
+                let mut sum = qtb.data[i][col];
+                for j in (i + 1)..COLS {
+                    sum -= r[i][j] * data[j][col];
+                }
+                data[i][col] = sum / r[i][i];
+            }
+        }
+        Ok(Matrix::new(data))
+    }
+
+    // 最小二乘求解 min‖A·x − b‖
+    pub fn solve_vector(&self, b: &[f64; ROWS]) -> Result<[f64; COLS], OperationError> {
+        let mut rhs = [[0.0; 1]; ROWS];
+        for row in 0..ROWS {
+            rhs[row][0] = b[row];
+        }
+        let x = self.solve(&Matrix::new(rhs))?;
+        let mut result = [0.0; COLS];
+        for row in 0..COLS {
+            result[row] = x.data[row][0];
+        }
+        Ok(result)
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> Matrix<ROWS, COLS, f64> {
+    // Householder 变换求 QR 分解
+    pub fn qr(&self) -> QRDecomposition<ROWS, COLS> {
+        let mut r = self.data;
+        let mut q = [[0.0; ROWS]; ROWS];
+        for i in 0..ROWS {
+            q[i][i] = 1.0;
+        }
+        for k in 0..COLS.min(ROWS.saturating_sub(1)) {
+            let norm = (k..ROWS).map(|row| r[row][k] * r[row][k]).sum::<f64>().sqrt();
+            if norm == 0.0 {
+                continue;
+            }
+            let alpha = if r[k][k] > 0.0 { -norm } else { norm };
+            let mut v = [0.0; ROWS];
+            for row in k..ROWS {
+                v[row] = r[row][k];
+            }
+            v[k] -= alpha;
+            let v_norm2: f64 = (k..ROWS).map(|row| v[row] * v[row]).sum();
+            if v_norm2 == 0.0 {
+                continue;
+            }
+            // R ← H·R，H = I − 2·v·vᵀ/(vᵀv)
+            for col in 0..COLS {
+                let dot: f64 = (k..ROWS).map(|row| v[row] * r[row][col]).sum();
+                let factor = 2.0 * dot / v_norm2;
+                for row in k..ROWS {
+                    r[row][col] -= factor * v[row];
+                }
+            }
+            // Q ← Q·H
+            for row in q.iter_mut() {
+                let dot: f64 = (k..ROWS).map(|i| row[i] * v[i]).sum();
+                let factor = 2.0 * dot / v_norm2;
+                for i in k..ROWS {
+                    row[i] -= factor * v[i];
+                }
+            }
+            for row in (k + 1)..ROWS {
+                r[row][k] = 0.0;
+            }
+        }
+        QRDecomposition {
+            q: Matrix::new(q),
+            r: Matrix::new(r),
+        }
+    }
+
+    // 基于 QR 分解的最小二乘解
+    pub fn least_squares<const K: usize>(
+        &self,
+        b: &Matrix<ROWS, K, f64>,
+    ) -> Result<Matrix<COLS, K, f64>, OperationError> {
+        self.qr().solve(b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn assert_qr<const R: usize, const C: usize>(m: &Matrix<R, C, f64>) {
+        let qr = m.qr();
+        let qtq = qr.q.T().product(&qr.q).unwrap();
+        for row in 0..R {
+            for col in 0..R {
+                let expected = if row == col { 1.0 } else { 0.0 };
+                assert_relative_eq!(qtq.data[row][col], expected, epsilon = 1e-12);
+            }
+        }
+        let rebuilt = qr.q.product(&qr.r).unwrap();
+        for row in 0..R {
+            for col in 0..C {
+                assert_relative_eq!(rebuilt.data[row][col], m.data[row][col], epsilon = 1e-10);
+                if row > col {
+                    assert_eq!(qr.r.data[row][col], 0.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_qr_square() {
+        let m = Matrix::<3, 3, f64>::new([[12.0, -51.0, 4.0], [6.0, 167.0, -68.0], [-4.0, 24.0, -41.0]]);
+        assert_qr(&m);
+        assert_relative_eq!(m.qr().r.data[0][0].abs(), 14.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_qr_rectangular() {
+        let tall = Matrix::<4, 2, f64>::new([[1.0, 1.0], [1.0, 2.0], [1.0, 3.0], [1.0, 4.0]]);
+        assert_qr(&tall);
+        assert_qr(&tall.T());
+    }
+
+    #[test]
+    fn test_least_squares() {
+        // 拟合 y = 1 + 2x
+        let a = Matrix::<4, 2, f64>::new([[1.0, 1.0], [1.0, 2.0], [1.0, 3.0], [1.0, 4.0]]);
+        let b = Matrix::<4, 1, f64>::new([[3.1], [4.9], [7.1], [8.9]]);
+        let x = a.least_squares(&b).unwrap();
+        assert_relative_eq!(x.data[0][0], 1.1, epsilon = 1e-10);
+        assert_relative_eq!(x.data[1][0], 1.96, epsilon = 1e-10);
+
+        let x = a.qr().solve_vector(&[3.0, 5.0, 7.0, 9.0]).unwrap();
+        assert_relative_eq!(x[0], 1.0, epsilon = 1e-10);
+        assert_relative_eq!(x[1], 2.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_least_squares_errors() {
+        let wide = Matrix::<2, 3, f64>::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b = Matrix::<2, 1, f64>::new([[1.0], [2.0]]);
+        assert_eq!(
+            wide.least_squares(&b).unwrap_err(),
+            OperationError::DimensionMismatch {
+                expected: (3, 3),
+                found: (2, 3)
+            }
+        );
+
+        let deficient = Matrix::<3, 2, f64>::new([[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]]);
+        let b = Matrix::<3, 1, f64>::new([[1.0], [2.0], [3.0]]);
+        let err = deficient.least_squares(&b).unwrap_err();
+        assert_eq!(err, OperationError::RankDeficient);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::dense::Matrix;
+use array_init::array_init;
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+
+// 序列化为按行嵌套的序列 [[a11, a12], [a21, a22]]，digits 不参与序列化
+impl<const ROWS: usize, const COLS: usize, T> Serialize for Matrix<ROWS, COLS, T>
+where
+    T: Copy + Send + Sync + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(ROWS))?;
+        for row in self.data.iter() {
+            seq.serialize_element(row.as_slice())?;
+        }
+        seq.end()
+    }
+}
+
+struct MatrixVisitor<const ROWS: usize, const COLS: usize, T> {
+    marker: PhantomData<T>,
+}
+
+impl<'de, const ROWS: usize, const COLS: usize, T> Visitor<'de> for MatrixVisitor<ROWS, COLS, T>
+where
+    T: Copy + Send + Sync + Deserialize<'de>,
+{
+    type Value = Matrix<ROWS, COLS, T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a matrix of {} rows with {} elements each", ROWS, COLS)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut rows: Vec<Vec<T>> = Vec::with_capacity(ROWS);
+        while let Some(row) = seq.next_element::<Vec<T>>()? {
+            if row.len() != COLS {
+                return Err(de::Error::invalid_length(row.len(), &self));
+            }
+            rows.push(row);
+        }
+        if rows.len() != ROWS {
+            return Err(de::Error::invalid_length(rows.len(), &self));
+        }
+        let data = array_init(|row| array_init(|col| rows[row][col]));
+        Ok(Matrix { data, digits: 0 })
+    }
+}
+
+impl<'de, const ROWS: usize, const COLS: usize, T> Deserialize<'de> for Matrix<ROWS, COLS, T>
+where
+    T: Copy + Send + Sync + Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(MatrixVisitor::<ROWS, COLS, T> {
+            marker: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matrix_round_trip() {
+        let m = Matrix::<2, 3, f64>::new([[1.0, 2.5, -3.0], [4.0, 5.0, 6.25]]);
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(json, "[[1.0,2.5,-3.0],[4.0,5.0,6.25]]");
+        let back: Matrix<2, 3, f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.data, m.data);
+
+        let m = Matrix::<1, 2, i32>::new([[7, -8]]);
+        let back: Matrix<1, 2, i32> = serde_json::from_str(&serde_json::to_string(&m).unwrap()).unwrap();
+        assert_eq!(back.data, m.data);
+    }
+
+    #[test]
+    fn test_matrix_shape_mismatch() {
+        assert!(serde_json::from_str::<Matrix<2, 2, f64>>("[[1.0,2.0],[3.0]]").is_err());
+        assert!(serde_json::from_str::<Matrix<2, 2, f64>>("[[1.0,2.0]]").is_err());
+        assert!(serde_json::from_str::<Matrix<2, 2, f64>>("[[1.0,2.0],[3.0,4.0],[5.0,6.0]]").is_err());
+    }
+}
@@ -23,20 +23,11 @@ where
         + From<i8>,
     f64: From<T>,
 {
-    pub fn T(&self) -> Matrix<COLS, ROWS, T> {
-        let mut data = [[T::default(); ROWS]; COLS];
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                data[col][row] = self.data[row][col];
-            }
-        }
-        Matrix::new(data)
-    }
-
     pub fn reshape<const NEW_ROWS: usize, const NEW_COLS: usize>(&self) -> Result<Matrix<NEW_ROWS, NEW_COLS, T>, OperationError> {
         if ROWS * COLS != NEW_ROWS * NEW_COLS {
-            return Err(OperationError {
-                message: "shape size does not match".to_string(),
+            return Err(OperationError::LengthMismatch {
+                expected: ROWS * COLS,
+                found: NEW_ROWS * NEW_COLS,
             });
         }
         let mut new_data = [[T::default(); NEW_COLS]; NEW_ROWS];
@@ -49,34 +40,103 @@ where
         }
         Ok(Matrix::new(new_data))
     }
-    //
-    // pub fn hstack<const RHS_COLS: usize>(&self, rhs: &Matrix<ROWS, RHS_COLS, T>) -> Result<Matrix<ROWS, {COLS + RHS_COLS}, T>, OperationError> {
-    //     let mut data = [[T::default(); COLS + RHS_COLS]; ROWS];
-    //     for row in 0..ROWS {
-    //         for col in 0..COLS {
-    //             data[row][col] = self.data[row][col];
-    //         }
-    //         for col in 0..RHS_COLS {
-    //             data[row][COLS + col] = rhs.data[row][col];
-    //         }
-    //     }
-    //     Ok(Matrix::new(data))
-    // }
-    //
-    // pub fn vstack<const RHS_ROWS: usize>(&self, rhs: &Matrix<RHS_ROWS, COLS, T>) -> Result<Matrix<{ROWS + RHS_ROWS}, COLS, T>, OperationError> {
-    //     let mut data: [[T; COLS]; ROWS + RHS_ROWS] = vec![vec![T::default(); COLS].try_into().unwrap(); ROWS + RHS_ROWS].try_into().unwrap();
-    //     for row in 0..ROWS {
-    //         for col in 0..COLS {
-    //             data[row][col] = self.data[row][col];
-    //         }
-    //     }
-    //     for row in 0..RHS_ROWS {
-    //         for col in 0..COLS {
-    //             data[ROWS + row][col] = rhs.data[row][col];
-    //         }
-    //     }
-    //     Ok(Matrix::new(data))
-    // }
+}
+
+impl<const ROWS: usize, const COLS: usize, T> Matrix<ROWS, COLS, T>
+where
+    T: Copy + Default + Send + Sync,
+{
+    pub fn T(&self) -> Matrix<COLS, ROWS, T> {
+        let mut data = [[T::default(); ROWS]; COLS];
+        for row in 0..ROWS {
+            for col in 0..COLS {
+                data[col][row] = self.data[row][col];
+            }
+        }
+        Matrix { data, digits: 0 }
+    }
+}
+
+// 输出矩阵的尺寸需要显式给出（稳定版不支持 {COLS + RHS_COLS}），在运行时检查
+impl<const ROWS: usize, const COLS: usize, T> Matrix<ROWS, COLS, T>
+where
+    T: Copy + Default + Send + Sync,
+{
+    // 水平拼接 [self rhs]
+    pub fn hstack<const RHS_COLS: usize, const OUT_COLS: usize>(
+        &self,
+        rhs: &Matrix<ROWS, RHS_COLS, T>,
+    ) -> Result<Matrix<ROWS, OUT_COLS, T>, OperationError> {
+        if COLS + RHS_COLS != OUT_COLS {
+            return Err(OperationError::DimensionMismatch {
+                expected: (ROWS, COLS + RHS_COLS),
+                found: (ROWS, OUT_COLS),
+            });
+        }
+        let mut data = [[T::default(); OUT_COLS]; ROWS];
+        for row in 0..ROWS {
+            data[row][..COLS].copy_from_slice(&self.data[row]);
+            data[row][COLS..].copy_from_slice(&rhs.data[row]);
+        }
+        Ok(Matrix { data, digits: 0 })
+    }
+
+    // 垂直拼接 [self; rhs]
+    pub fn vstack<const RHS_ROWS: usize, const OUT_ROWS: usize>(
+        &self,
+        rhs: &Matrix<RHS_ROWS, COLS, T>,
+    ) -> Result<Matrix<OUT_ROWS, COLS, T>, OperationError> {
+        if ROWS + RHS_ROWS != OUT_ROWS {
+            return Err(OperationError::DimensionMismatch {
+                expected: (ROWS + RHS_ROWS, COLS),
+                found: (OUT_ROWS, COLS),
+            });
+        }
+        let mut data = [[T::default(); COLS]; OUT_ROWS];
+        data[..ROWS].copy_from_slice(&self.data);
+        data[ROWS..].copy_from_slice(&rhs.data);
+        Ok(Matrix { data, digits: 0 })
+    }
+
+    // 由四个子块组装分块矩阵
+    // ┌a b┐
+    // └c d┘
+    pub fn from_blocks<const R1: usize, const C1: usize, const R2: usize, const C2: usize>(
+        a: &Matrix<R1, C1, T>,
+        b: &Matrix<R1, C2, T>,
+        c: &Matrix<R2, C1, T>,
+        d: &Matrix<R2, C2, T>,
+    ) -> Result<Self, OperationError> {
+        if R1 + R2 != ROWS || C1 + C2 != COLS {
+            return Err(OperationError::DimensionMismatch {
+                expected: (ROWS, COLS),
+                found: (R1 + R2, C1 + C2),
+            });
+        }
+        let mut data = [[T::default(); COLS]; ROWS];
+        for row in 0..R1 {
+            data[row][..C1].copy_from_slice(&a.data[row]);
+            data[row][C1..].copy_from_slice(&b.data[row]);
+        }
+        for row in 0..R2 {
+            data[R1 + row][..C1].copy_from_slice(&c.data[row]);
+            data[R1 + row][C1..].copy_from_slice(&d.data[row]);
+        }
+        Ok(Matrix { data, digits: 0 })
+    }
+
+    // 分块对角矩阵 diag(a, b)，其余位置填 T::default()
+    pub fn block_diag<const R1: usize, const C1: usize, const R2: usize, const C2: usize>(
+        a: &Matrix<R1, C1, T>,
+        b: &Matrix<R2, C2, T>,
+    ) -> Result<Self, OperationError> {
+        Self::from_blocks(
+            a,
+            &Matrix { data: [[T::default(); C2]; R1], digits: 0 },
+            &Matrix { data: [[T::default(); C1]; R2], digits: 0 },
+            b,
+        )
+    }
 }
 
 impl<const ROWS: usize, const COLS: usize, T> Matrix<ROWS, COLS, T>
@@ -138,23 +198,61 @@ mod tests {
         assert_eq!(m1.data, [[1, 2], [3, 4], [5, 6]]);
     }
 
-    // #[test]
-    // fn test_hstack() {
-    //     let m = Matrix::<2, 3, i32>::new([[1, 2, 3], [4, 5, 6]]);
-    //     let m1 = Matrix::<2, 4, i32>::new([[1, 2, 3, 4], [5, 6, 7, 8]]);
-    //     let mr = m.hstack(&m1).unwrap();
-    //     assert_eq!(mr.size(), (2, 7));
-    //     assert_eq!(mr.data, [[1, 2, 3, 1, 2, 3, 4], [4, 5, 6, 5, 6, 7, 8]]);
-    // }
-    //
-    // #[test]
-    // fn test_vstack() {
-    //     let m = Matrix::<2, 3, i32>::new([[1, 2, 3], [4, 5, 6]]);
-    //     let m1 = Matrix::<3, 3, i32>::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
-    //     let mr = m.vstack(&m1).unwrap();
-    //     assert_eq!(mr.size(), (5, 3));
-    //     assert_eq!(mr.data, [[1, 2, 3], [4, 5, 6], [1, 2, 3], [4, 5, 6], [7, 8, 9]]);
-    // }
+    #[test]
+    fn test_hstack() {
+        let m = Matrix::<2, 3, i32>::new([[1, 2, 3], [4, 5, 6]]);
+        let m1 = Matrix::<2, 4, i32>::new([[1, 2, 3, 4], [5, 6, 7, 8]]);
+        let mr: Matrix<2, 7, i32> = m.hstack(&m1).unwrap();
+        assert_eq!(mr.size(), (2, 7));
+        assert_eq!(mr.data, [[1, 2, 3, 1, 2, 3, 4], [4, 5, 6, 5, 6, 7, 8]]);
+
+        let err = m.hstack::<4, 6>(&m1).unwrap_err();
+        assert_eq!(
+            err,
+            OperationError::DimensionMismatch {
+                expected: (2, 7),
+                found: (2, 6)
+            }
+        );
+    }
+
+    #[test]
+    fn test_vstack() {
+        let m = Matrix::<2, 3, i32>::new([[1, 2, 3], [4, 5, 6]]);
+        let m1 = Matrix::<3, 3, i32>::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let mr: Matrix<5, 3, i32> = m.vstack(&m1).unwrap();
+        assert_eq!(mr.size(), (5, 3));
+        assert_eq!(mr.data, [[1, 2, 3], [4, 5, 6], [1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+
+        assert!(m.vstack::<3, 4>(&m1).is_err());
+    }
+
+    #[test]
+    fn test_from_blocks() {
+        let identity = Matrix::<3, 3, f64>::unit();
+        let dt = Matrix::<3, 3, f64>::unit().scale(0.1);
+        let zero = Matrix::<3, 3, f64>::zeros();
+        let phi = Matrix::<6, 6, f64>::from_blocks(&identity, &dt, &zero, &identity).unwrap();
+        assert_eq!(phi.data[0], [1.0, 0.0, 0.0, 0.1, 0.0, 0.0]);
+        assert_eq!(phi.data[4], [0.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+
+        let a = Matrix::<1, 2, i32>::new([[1, 2]]);
+        let b = Matrix::<1, 1, i32>::new([[3]]);
+        let c = Matrix::<2, 2, i32>::new([[4, 5], [7, 8]]);
+        let d = Matrix::<2, 1, i32>::new([[6], [9]]);
+        let m = Matrix::<3, 3, i32>::from_blocks(&a, &b, &c, &d).unwrap();
+        assert_eq!(m.data, [[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+
+        assert!(Matrix::<3, 4, i32>::from_blocks(&a, &b, &c, &d).is_err());
+    }
+
+    #[test]
+    fn test_block_diag() {
+        let a = Matrix::<1, 1, i32>::new([[1]]);
+        let b = Matrix::<2, 2, i32>::new([[2, 3], [4, 5]]);
+        let m = Matrix::<3, 3, i32>::block_diag(&a, &b).unwrap();
+        assert_eq!(m.data, [[1, 0, 0], [0, 2, 3], [0, 4, 5]]);
+    }
 
     #[test]
     fn test_sum_vertical_basic() {
@@ -237,7 +335,7 @@ mod tests {
         let m = Matrix::<0, 0, f64>::new([[]; 0]);
         let result = m.sum_column();
         assert_eq!(result.size(), (1, 0));
-        assert_eq!(result.data, [[]]);
+        assert_eq!(result.data, [[0.0f64; 0]]);
     }
 
     #[test]
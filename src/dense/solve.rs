@@ -0,0 +1,173 @@
+use crate::constant::get_flt64_zero;
+use crate::dense::error::OperationError;
+use crate::dense::{LUDecomposition, Matrix};
+
+impl<const N: usize> Matrix<N, N, f64> {
+    // 矩阵的 1-范数：各列绝对值之和的最大值
+    pub(crate) fn norm_1_of(data: &[[f64; N]; N]) -> f64 {
+        let mut max = 0.0;
+        for col in 0..N {
+            let sum: f64 = data.iter().map(|row| row[col].abs()).sum();
+            if sum > max {
+                max = sum;
+            }
+        }
+        max
+    }
+
+    // 由条件数估计判断：无穷大（主元为零）为奇异矩阵，κ₁(A)·get_flt64_zero() ≥ 1 为病态矩阵
+    fn check_condition(cond: f64) -> Result<(), OperationError> {
+        if !cond.is_finite() {
+            return Err(OperationError::Singular { condition: cond });
+        }
+        if cond * get_flt64_zero() >= 1.0 {
+            return Err(OperationError::IllConditioned { condition: cond });
+        }
+        Ok(())
+    }
+
+    // 分解并用 LU 因子估计 κ₁(A)，不构造逆矩阵
+    fn checked_lu(&self) -> Result<LUDecomposition<N>, OperationError> {
+        let lu = self.lu()?;
+        let cond = Self::norm_1_of(&self.data) * lu.inverse_norm_1_estimate();
+        Self::check_condition(cond)?;
+        Ok(lu)
+    }
+
+    // 1-范数条件数 κ₁(A) = ‖A‖₁·‖A⁻¹‖₁，奇异矩阵返回无穷大
+    pub fn condition_number_1(&self) -> f64 {
+        match self.lu() {
+            Ok(lu) => Self::norm_1_of(&self.data) * Self::norm_1_of(&lu.inverse().data),
+            Err(_) => f64::INFINITY,
+        }
+    }
+
+    // 求逆矩阵，奇异或病态（κ₁·get_flt64_zero() ≥ 1）时返回错误
+    pub fn inverse(&self) -> Result<Matrix<N, N, f64>, OperationError> {
+        let inverse = self.lu()?.inverse();
+        Self::check_condition(Self::norm_1_of(&self.data) * Self::norm_1_of(&inverse.data))?;
+        Ok(inverse)
+    }
+
+    // 求解 A·X = B，B 的每一列是一个右端项
+    pub fn solve<const K: usize>(
+        &self,
+        b: &Matrix<N, K, f64>,
+    ) -> Result<Matrix<N, K, f64>, OperationError> {
+        let lu = self.checked_lu()?;
+        let mut data = [[0.0; K]; N];
+        for col in 0..K {
+            let mut rhs = [0.0; N];
+            for row in 0..N {
+                rhs[row] = b.data[row][col];
+            }
+            let x = lu.solve_vector(&rhs);
+            for row in 0..N {
+                data[row][col] = x[row];
+            }
+        }
+        Ok(Matrix::new(data))
+    }
+
+    // 求解 A·x = b
+    pub fn solve_vector(&self, b: &[f64; N]) -> Result<[f64; N], OperationError> {
+        let lu = self.checked_lu()?;
+        Ok(lu.solve_vector(b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_inverse() {
+        let m = Matrix::<2, 2, f64>::new([[4.0, 7.0], [2.0, 6.0]]);
+        let inv = m.inverse().unwrap();
+        assert_relative_eq!(inv.data[0][0], 0.6, epsilon = 1e-12);
+        assert_relative_eq!(inv.data[0][1], -0.7, epsilon = 1e-12);
+        assert_relative_eq!(inv.data[1][0], -0.2, epsilon = 1e-12);
+        assert_relative_eq!(inv.data[1][1], 0.4, epsilon = 1e-12);
+
+        let identity = m.product(&inv).unwrap();
+        for row in 0..2 {
+            for col in 0..2 {
+                let expected = if row == col { 1.0 } else { 0.0 };
+                assert_relative_eq!(identity.data[row][col], expected, epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_singular() {
+        let m = Matrix::<2, 2, f64>::new([[1.0, 2.0], [2.0, 4.0]]);
+        let err = m.inverse().unwrap_err();
+        assert_eq!(
+            err,
+            OperationError::Singular {
+                condition: f64::INFINITY
+            }
+        );
+        assert_eq!(err.to_string(), "matrix is singular (condition number estimate: inf)");
+    }
+
+    #[test]
+    fn test_inverse_ill_conditioned() {
+        let m = Matrix::<2, 2, f64>::new([[1.0, 1.0], [1.0, 1.0 + 2e-14]]);
+        let err = m.inverse().unwrap_err();
+        assert!(matches!(err, OperationError::IllConditioned { condition } if condition > 1e14));
+        assert!(m.condition_number_1() > 1e14);
+    }
+
+    #[test]
+    fn test_scaled_identity_is_well_conditioned() {
+        let m = Matrix::<3, 3, f64>::unit().scale(1e-15);
+        assert_relative_eq!(m.condition_number_1(), 1.0);
+        let inv = m.inverse().unwrap();
+        assert_relative_eq!(inv.data[1][1], 1e15, max_relative = 1e-12);
+        let x = m.solve_vector(&[1e-15, 2e-15, 3e-15]).unwrap();
+        assert_relative_eq!(x[2], 3.0, max_relative = 1e-12);
+        let b = Matrix::<3, 1, f64>::new([[1e-15], [0.0], [-1e-15]]);
+        assert_relative_eq!(m.solve(&b).unwrap().data[2][0], -1.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn test_solve_ill_conditioned() {
+        let m = Matrix::<2, 2, f64>::new([[1.0, 1.0], [1.0, 1.0 + 2e-14]]);
+        let err = m.solve_vector(&[1.0, 1.0]).unwrap_err();
+        assert!(matches!(err, OperationError::IllConditioned { condition } if condition > 1e14));
+    }
+
+    #[test]
+    fn test_solve() {
+        let a = Matrix::<3, 3, f64>::new([[2.0, 1.0, 1.0], [4.0, -6.0, 0.0], [-2.0, 7.0, 2.0]]);
+        let b = Matrix::<3, 2, f64>::new([[5.0, 4.0], [-2.0, -2.0], [9.0, 7.0]]);
+        let x = a.solve(&b).unwrap();
+        let expected = [[1.0, 1.0], [1.0, 1.0], [2.0, 1.0]];
+        for row in 0..3 {
+            for col in 0..2 {
+                assert_relative_eq!(x.data[row][col], expected[row][col], epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_vector() {
+        let a = Matrix::<2, 2, f64>::new([[3.0, 2.0], [1.0, 2.0]]);
+        let x = a.solve_vector(&[7.0, 5.0]).unwrap();
+        assert_relative_eq!(x[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(x[1], 2.0, epsilon = 1e-12);
+
+        let singular = Matrix::<2, 2, f64>::new([[1.0, 2.0], [2.0, 4.0]]);
+        assert!(singular.solve_vector(&[1.0, 1.0]).is_err());
+    }
+
+    #[test]
+    fn test_condition_number_1() {
+        let m = Matrix::<2, 2, f64>::unit();
+        assert_relative_eq!(m.condition_number_1(), 1.0);
+        let singular = Matrix::<2, 2, f64>::new([[1.0, 2.0], [2.0, 4.0]]);
+        assert!(singular.condition_number_1().is_infinite());
+    }
+}
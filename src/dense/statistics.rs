@@ -0,0 +1,297 @@
+use crate::dense::error::OperationError;
+use crate::dense::Matrix;
+use std::ops::Add;
+
+impl<const N: usize, T> Matrix<N, N, T>
+where
+    T: Copy + Send + Sync + Default + Add<Output = T>,
+{
+    // 迹：对角线元素之和
+    pub fn trace(&self) -> T {
+        let mut sum = T::default();
+        for i in 0..N {
+            sum = sum + self.data[i][i];
+        }
+        sum
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, T> Matrix<ROWS, COLS, T>
+where
+    T: Copy + Send + Sync + PartialOrd,
+{
+    // 最大元素，空矩阵返回 None
+    pub fn max(&self) -> Option<T> {
+        self.argmax().map(|(row, col)| self.data[row][col])
+    }
+
+    // 最小元素，空矩阵返回 None
+    pub fn min(&self) -> Option<T> {
+        self.argmin().map(|(row, col)| self.data[row][col])
+    }
+
+    // 最大元素的 (行, 列)，有多个时取行优先顺序的第一个
+    pub fn argmax(&self) -> Option<(usize, usize)> {
+        self.arg_by(|candidate, best| candidate > best)
+    }
+
+    // 最小元素的 (行, 列)，有多个时取行优先顺序的第一个
+    pub fn argmin(&self) -> Option<(usize, usize)> {
+        self.arg_by(|candidate, best| candidate < best)
+    }
+
+    fn arg_by<F>(&self, better: F) -> Option<(usize, usize)>
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        let mut best: Option<(usize, usize)> = None;
+        for row in 0..ROWS {
+            for col in 0..COLS {
+                match best {
+                    Some((r, c)) if !better(&self.data[row][col], &self.data[r][c]) => {}
+                    _ => best = Some((row, col)),
+                }
+            }
+        }
+        best
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, T> Matrix<ROWS, COLS, T>
+where
+    T: Copy + Send + Sync,
+    f64: From<T>,
+{
+    // Frobenius 范数 sqrt(Σ aᵢⱼ²)
+    pub fn norm_frobenius(&self) -> f64 {
+        self.fold(0.0, |sum, value| {
+            let v = f64::from(value);
+            sum + v * v
+        })
+        .sqrt()
+    }
+
+    // 1-范数：列绝对值和的最大值
+    pub fn norm_1(&self) -> f64 {
+        let mut max = 0.0;
+        for col in 0..COLS {
+            let sum: f64 = self.data.iter().map(|row| f64::from(row[col]).abs()).sum();
+            if sum > max {
+                max = sum;
+            }
+        }
+        max
+    }
+
+    // ∞-范数：行绝对值和的最大值
+    pub fn norm_inf(&self) -> f64 {
+        let mut max = 0.0;
+        for row in self.data.iter() {
+            let sum: f64 = row.iter().map(|&value| f64::from(value).abs()).sum();
+            if sum > max {
+                max = sum;
+            }
+        }
+        max
+    }
+
+    // 谱范数（2-范数）：最大奇异值，向量的 2-范数等于 Frobenius 范数
+    pub fn norm_2(&self) -> Result<f64, OperationError> {
+        if ROWS == 1 || COLS == 1 {
+            return Ok(self.norm_frobenius());
+        }
+        let values = self.map(f64::from).singular_values()?;
+        Ok(values.first().cloned().unwrap_or(0.0))
+    }
+
+    // 每行的均值，返回列向量
+    pub fn mean_row(&self) -> Matrix<ROWS, 1, f64> {
+        let mut data = [[0.0; 1]; ROWS];
+        for (row, out) in data.iter_mut().enumerate() {
+            let sum: f64 = self.data[row].iter().map(|&value| f64::from(value)).sum();
+            out[0] = sum / COLS as f64;
+        }
+        Matrix { data, digits: 0 }
+    }
+
+    // 每列的均值，返回行向量
+    pub fn mean_column(&self) -> Matrix<1, COLS, f64> {
+        let mut data = [[0.0; COLS]; 1];
+        for col in 0..COLS {
+            let sum: f64 = self.data.iter().map(|row| f64::from(row[col])).sum();
+            data[0][col] = sum / ROWS as f64;
+        }
+        Matrix { data, digits: 0 }
+    }
+
+    // 每行的方差，除以 COLS − ddof：ddof = 0 为总体方差，ddof = 1 为样本方差，返回列向量
+    pub fn variance_row(&self, ddof: usize) -> Result<Matrix<ROWS, 1, f64>, OperationError> {
+        let divisor = divisor(COLS, ddof)?;
+        let mean = self.mean_row();
+        let mut data = [[0.0; 1]; ROWS];
+        for (row, out) in data.iter_mut().enumerate() {
+            let m = mean.data[row][0];
+            let sum: f64 = self.data[row]
+                .iter()
+                .map(|&value| (f64::from(value) - m).powi(2))
+                .sum();
+            out[0] = sum / divisor;
+        }
+        Ok(Matrix { data, digits: 0 })
+    }
+
+    // 每列的方差，除以 ROWS − ddof，返回行向量
+    pub fn variance_column(&self, ddof: usize) -> Result<Matrix<1, COLS, f64>, OperationError> {
+        let divisor = divisor(ROWS, ddof)?;
+        let mean = self.mean_column();
+        let mut data = [[0.0; COLS]; 1];
+        for col in 0..COLS {
+            let m = mean.data[0][col];
+            let sum: f64 = self
+                .data
+                .iter()
+                .map(|row| (f64::from(row[col]) - m).powi(2))
+                .sum();
+            data[0][col] = sum / divisor;
+        }
+        Ok(Matrix { data, digits: 0 })
+    }
+
+    // 每行的标准差，ddof 含义同 variance_row
+    pub fn std_row(&self, ddof: usize) -> Result<Matrix<ROWS, 1, f64>, OperationError> {
+        Ok(self.variance_row(ddof)?.map(f64::sqrt))
+    }
+
+    // 每列的标准差，ddof 含义同 variance_column
+    pub fn std_column(&self, ddof: usize) -> Result<Matrix<1, COLS, f64>, OperationError> {
+        Ok(self.variance_column(ddof)?.map(f64::sqrt))
+    }
+
+    // 协方差矩阵（除以 ROWS − ddof），每行是一个样本，每列是一个变量；
+    // 对角线与 variance_column(ddof) 一致
+    pub fn covariance(&self, ddof: usize) -> Result<Matrix<COLS, COLS, f64>, OperationError> {
+        let divisor = divisor(ROWS, ddof)?;
+        let mean = self.mean_column();
+        let mut data = [[0.0; COLS]; COLS];
+        for row in self.data.iter() {
+            for i in 0..COLS {
+                let di = f64::from(row[i]) - mean.data[0][i];
+                for j in i..COLS {
+                    data[i][j] += di * (f64::from(row[j]) - mean.data[0][j]);
+                }
+            }
+        }
+        for i in 0..COLS {
+            for j in i..COLS {
+                data[i][j] /= divisor;
+                data[j][i] = data[i][j];
+            }
+        }
+        Ok(Matrix { data, digits: 0 })
+    }
+}
+
+// 自由度 n − ddof 必须为正
+fn divisor(n: usize, ddof: usize) -> Result<f64, OperationError> {
+    if n <= ddof {
+        return Err(OperationError::TooFewSamples {
+            samples: n,
+            required: ddof + 1,
+        });
+    }
+    Ok((n - ddof) as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_trace() {
+        let m = Matrix::<3, 3, i32>::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(m.trace(), 15);
+        assert_relative_eq!(Matrix::<4, 4, f64>::unit().trace(), 4.0);
+    }
+
+    #[test]
+    fn test_max_min() {
+        let m = Matrix::<2, 3, i32>::new([[3, -1, 7], [7, 0, -5]]);
+        assert_eq!(m.max(), Some(7));
+        assert_eq!(m.min(), Some(-5));
+        assert_eq!(m.argmax(), Some((0, 2)));
+        assert_eq!(m.argmin(), Some((1, 2)));
+        let empty = Matrix::<0, 0, i32>::new([]);
+        assert_eq!(empty.max(), None);
+        assert_eq!(empty.argmin(), None);
+    }
+
+    #[test]
+    fn test_norms() {
+        let m = Matrix::<2, 2, f64>::new([[1.0, -2.0], [-3.0, 4.0]]);
+        assert_relative_eq!(m.norm_frobenius(), 30f64.sqrt());
+        assert_relative_eq!(m.norm_1(), 6.0);
+        assert_relative_eq!(m.norm_inf(), 7.0);
+        assert_relative_eq!(m.norm_2().unwrap(), 5.464985704219043, epsilon = 1e-12);
+
+        let m = Matrix::<1, 3, i32>::new([[3, 4, 0]]);
+        assert_relative_eq!(m.norm_frobenius(), 5.0);
+        assert_relative_eq!(m.norm_2().unwrap(), 5.0, epsilon = 1e-12);
+
+        let column = Matrix::<200, 1, f64>::new([[0.5]; 200]);
+        assert_relative_eq!(column.norm_2().unwrap(), 50f64.sqrt(), epsilon = 1e-12);
+        assert_relative_eq!(column.norm_2().unwrap(), column.norm_frobenius());
+        let m = Matrix::<3, 2, f64>::new([[1.0, 0.0], [0.0, 2.0], [0.0, 0.0]]);
+        assert_relative_eq!(m.norm_2().unwrap(), 2.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_mean_and_variance() {
+        let m = Matrix::<2, 4, f64>::new([[2.0, 4.0, 4.0, 6.0], [1.0, 1.0, 1.0, 1.0]]);
+        assert_eq!(m.mean_row().data, [[4.0], [1.0]]);
+        assert_eq!(m.mean_column().data, [[1.5, 2.5, 2.5, 3.5]]);
+        assert_eq!(m.variance_row(0).unwrap().data, [[2.0], [0.0]]);
+        assert_eq!(m.variance_column(0).unwrap().data, [[0.25, 2.25, 2.25, 6.25]]);
+        assert_eq!(m.std_row(0).unwrap().data, [[2f64.sqrt()], [0.0]]);
+        assert_eq!(m.std_column(0).unwrap().data, [[0.5, 1.5, 1.5, 2.5]]);
+
+        // 样本方差
+        assert_eq!(m.variance_row(1).unwrap().data, [[8.0 / 3.0], [0.0]]);
+        assert_eq!(m.variance_column(1).unwrap().data, [[0.5, 4.5, 4.5, 12.5]]);
+        assert!(m.variance_column(2).is_err());
+        assert!(m.std_row(4).is_err());
+    }
+
+    #[test]
+    fn test_covariance() {
+        let samples = Matrix::<4, 2, f64>::new([[1.0, 2.0], [2.0, 4.0], [3.0, 6.0], [4.0, 8.0]]);
+        let cov = samples.covariance(1).unwrap();
+        let var = 5.0 / 3.0;
+        assert_relative_eq!(cov.data[0][0], var, epsilon = 1e-12);
+        assert_relative_eq!(cov.data[0][1], 2.0 * var, epsilon = 1e-12);
+        assert_relative_eq!(cov.data[1][0], 2.0 * var, epsilon = 1e-12);
+        assert_relative_eq!(cov.data[1][1], 4.0 * var, epsilon = 1e-12);
+
+        let single = Matrix::<1, 2, f64>::new([[1.0, 2.0]]);
+        assert_eq!(
+            single.covariance(1).unwrap_err(),
+            OperationError::TooFewSamples {
+                samples: 1,
+                required: 2
+            }
+        );
+        assert!(single.covariance(0).is_ok());
+    }
+
+    #[test]
+    fn test_covariance_diagonal_matches_variance() {
+        let m = Matrix::<3, 2, f64>::new([[1.0, 2.0], [2.0, 4.5], [3.0, 5.5]]);
+        for ddof in 0..2 {
+            let cov = m.covariance(ddof).unwrap();
+            let var = m.variance_column(ddof).unwrap();
+            for i in 0..2 {
+                assert_relative_eq!(cov.data[i][i], var.data[0][i], epsilon = 1e-12);
+            }
+        }
+    }
+}
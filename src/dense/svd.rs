@@ -0,0 +1,370 @@
+use crate::constant::get_flt64_zero;
+use crate::dense::error::OperationError;
+use crate::dense::Matrix;
+
+const MAX_JACOBI_SWEEPS: usize = 100;
+
+// 单边 Jacobi 的结果 (W, V)
+type JacobiColumns<const ROWS: usize, const COLS: usize> =
+    ([[f64; COLS]; ROWS], [[f64; COLS]; COLS]);
+
+// 奇异值分解：A = U·Σ·Vᵀ
+#[derive(Clone, Copy, Debug)]
+pub struct SVD<const ROWS: usize, const COLS: usize> {
+    // ROWS×ROWS 正交矩阵
+    pub u: Matrix<ROWS, ROWS, f64>,
+    // 对角线上为按从大到小排序的奇异值
+    pub sigma: Matrix<ROWS, COLS, f64>,
+    // COLS×COLS 正交矩阵
+    pub vt: Matrix<COLS, COLS, f64>,
+}
+
+impl<const ROWS: usize, const COLS: usize> SVD<ROWS, COLS> {
+    // 奇异值，长度为 min(ROWS, COLS)，从大到小排列
+    pub fn singular_values(&self) -> Vec<f64> {
+        (0..ROWS.min(COLS)).map(|i| self.sigma.data[i][i]).collect()
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> Matrix<ROWS, COLS, f64> {
+    // 单边 Jacobi 法（Hestenes）正交化各列，返回 W = A·V 与 V；with_v 为 false 时不累积 V
+    fn jacobi_columns(&self, with_v: bool) -> Result<JacobiColumns<ROWS, COLS>, OperationError> {
+        let mut w = self.data;
+        let mut v = [[0.0; COLS]; COLS];
+        for i in 0..COLS {
+            v[i][i] = 1.0;
+        }
+        let tolerance = get_flt64_zero();
+        // 范数平方低于该值的列视为零列，不再参与旋转
+        let frobenius2: f64 = w.iter().flat_map(|row| row.iter()).map(|x| x * x).sum();
+        let negligible = tolerance * tolerance * frobenius2;
+        for _ in 0..MAX_JACOBI_SWEEPS {
+            let mut rotated = false;
+            for i in 0..COLS {
+                for j in (i + 1)..COLS {
+                    let mut alpha = 0.0;
+                    let mut beta = 0.0;
+                    let mut gamma = 0.0;
+                    for row in w.iter() {
+                        alpha += row[i] * row[i];
+                        beta += row[j] * row[j];
+                        gamma += row[i] * row[j];
+                    }
+                    if alpha <= negligible
+                        || beta <= negligible
+                        || gamma.abs() <= tolerance * (alpha * beta).sqrt()
+                    {
+                        continue;
+                    }
+                    rotated = true;
+                    let zeta = (beta - alpha) / (2.0 * gamma);
+                    let t = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                    let c = 1.0 / (1.0 + t * t).sqrt();
+                    let s = c * t;
+                    for row in w.iter_mut() {
+                        let wi = row[i];
+                        let wj = row[j];
+                        row[i] = c * wi - s * wj;
+                        row[j] = s * wi + c * wj;
+                    }
+                    if !with_v {
+                        continue;
+                    }
+                    for row in v.iter_mut() {
+                        let vi = row[i];
+                        let vj = row[j];
+                        row[i] = c * vi - s * vj;
+                        row[j] = s * vi + c * vj;
+                    }
+                }
+            }
+            if !rotated {
+                return Ok((w, v));
+            }
+        }
+        Err(OperationError::NotConverged {
+            algorithm: "svd",
+            iterations: MAX_JACOBI_SWEEPS,
+        })
+    }
+
+    // 奇异值分解 A = U·Σ·Vᵀ
+    pub fn svd(&self) -> Result<SVD<ROWS, COLS>, OperationError> {
+        let (w, v) = self.jacobi_columns(true)?;
+        let (norms, order) = column_norms(&w);
+        let k = ROWS.min(COLS);
+        let sigma_max = if COLS > 0 { norms[order[0]] } else { 0.0 };
+        let zero = get_flt64_zero() * sigma_max;
+        let mut u = [[0.0; ROWS]; ROWS];
+        let mut sigma = [[0.0; COLS]; ROWS];
+        let mut vt = [[0.0; COLS]; COLS];
+        let mut filled = 0;
+        for (dst, &src) in order.iter().enumerate() {
+            for col in 0..COLS {
+                vt[dst][col] = v[col][src];
+            }
+            if dst >= k {
+                continue;
+            }
+            sigma[dst][dst] = norms[src];
+            if norms[src] > zero {
+                for row in 0..ROWS {
+                    u[row][dst] = w[row][src] / norms[src];
+                }
+                filled = dst + 1;
+            }
+        }
+        complete_orthonormal_columns(&mut u, filled);
+        Ok(SVD {
+            u: Matrix::new(u),
+            sigma: Matrix::new(sigma),
+            vt: Matrix::new(vt),
+        })
+    }
+
+    // 只求奇异值（不构造 U、V），长度为 min(ROWS, COLS)，从大到小排列
+    pub(crate) fn singular_values(&self) -> Result<Vec<f64>, OperationError> {
+        // 宽矩阵转置后列数更少，Jacobi 旋转的次数也更少
+        if ROWS < COLS {
+            return self.T().singular_values();
+        }
+        let (w, _) = self.jacobi_columns(false)?;
+        let (norms, order) = column_norms(&w);
+        Ok(order
+            .iter()
+            .take(ROWS.min(COLS))
+            .map(|&i| norms[i])
+            .collect())
+    }
+
+    // Moore–Penrose 伪逆，小于 get_flt64_zero()·max(ROWS, COLS)·σmax 的奇异值视为零
+    // A⁺ = Σ vᵢ·uᵢᵀ/σᵢ，其中 uᵢ = wᵢ/σᵢ，不需要补全 U
+    pub fn pinv(&self) -> Result<Matrix<COLS, ROWS, f64>, OperationError> {
+        let (w, v) = self.jacobi_columns(true)?;
+        let (norms, order) = column_norms(&w);
+        let sigma_max = if COLS > 0 { norms[order[0]] } else { 0.0 };
+        let tolerance = get_flt64_zero() * ROWS.max(COLS) as f64 * sigma_max;
+        let mut data = [[0.0; ROWS]; COLS];
+        for &i in order.iter().take(ROWS.min(COLS)) {
+            let value = norms[i];
+            if value <= tolerance {
+                continue;
+            }
+            let value2 = value * value;
+            for row in 0..COLS {
+                for col in 0..ROWS {
+                    data[row][col] += v[row][i] * w[col][i] / value2;
+                }
+            }
+        }
+        Ok(Matrix::new(data))
+    }
+
+    // 大于 tol 的奇异值个数
+    pub fn rank(&self, tol: f64) -> Result<usize, OperationError> {
+        let values = self.singular_values()?;
+        Ok(values.iter().filter(|&&value| value > tol).count())
+    }
+
+    // 2-范数条件数 σmax/σmin，最小奇异值为零时返回无穷大
+    pub fn condition_number(&self) -> Result<f64, OperationError> {
+        let values = self.singular_values()?;
+        let (max, min) = match (values.first(), values.last()) {
+            (Some(&max), Some(&min)) => (max, min),
+            _ => return Ok(0.0),
+        };
+        if min == 0.0 {
+            return Ok(f64::INFINITY);
+        }
+        Ok(max / min)
+    }
+}
+
+// W 各列的范数，以及按范数从大到小排列的列序号
+fn column_norms<const ROWS: usize, const COLS: usize>(
+    w: &[[f64; COLS]; ROWS],
+) -> ([f64; COLS], [usize; COLS]) {
+    let mut norms = [0.0; COLS];
+    for (col, norm) in norms.iter_mut().enumerate() {
+        *norm = w.iter().map(|row| row[col] * row[col]).sum::<f64>().sqrt();
+    }
+    let mut order = [0usize; COLS];
+    for (i, o) in order.iter_mut().enumerate() {
+        *o = i;
+    }
+    order.sort_by(|&i, &j| norms[j].total_cmp(&norms[i]));
+    (norms, order)
+}
+
+// 前 filled 列已是单位正交的矩阵补全为正交矩阵：
+// 对前 filled 列做 Householder QR，Q = H₀·H₁⋯ 的其余列与前 filled 列正交，复杂度 O(N²·filled)
+fn complete_orthonormal_columns<const N: usize>(u: &mut [[f64; N]; N], filled: usize) {
+    let mut a = *u;
+    let mut reflectors: Vec<[f64; N]> = Vec::with_capacity(filled);
+    for k in 0..filled {
+        let norm = (k..N).map(|row| a[row][k] * a[row][k]).sum::<f64>().sqrt();
+        let mut h = [0.0; N];
+        if norm > 0.0 {
+            let alpha = if a[k][k] > 0.0 { -norm } else { norm };
+            for row in k..N {
+                h[row] = a[row][k];
+            }
+            h[k] -= alpha;
+            let h_norm = h[k..].iter().map(|x| x * x).sum::<f64>().sqrt();
+            h[k..].iter_mut().for_each(|x| *x /= h_norm);
+            // H = I - 2·h·hᵀ 作用到剩余的列
+            for col in k..filled {
+                let dot: f64 = (k..N).map(|row| h[row] * a[row][col]).sum();
+                for (a_row, &h_row) in a[k..].iter_mut().zip(&h[k..]) {
+                    a_row[col] -= 2.0 * dot * h_row;
+                }
+            }
+        }
+        reflectors.push(h);
+    }
+    // Q 的第 col 列 = H₀·H₁⋯H_{filled-1}·e_col
+    for col in filled..N {
+        let mut r = [0.0; N];
+        r[col] = 1.0;
+        for (k, h) in reflectors.iter().enumerate().rev() {
+            let dot: f64 = (k..N).map(|row| h[row] * r[row]).sum();
+            for row in k..N {
+                r[row] -= 2.0 * dot * h[row];
+            }
+        }
+        for row in 0..N {
+            u[row][col] = r[row];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn assert_orthogonal<const N: usize>(m: &Matrix<N, N, f64>) {
+        let mtm = m.T().product(m).unwrap();
+        for row in 0..N {
+            for col in 0..N {
+                let expected = if row == col { 1.0 } else { 0.0 };
+                assert_relative_eq!(mtm.data[row][col], expected, epsilon = 1e-10);
+            }
+        }
+    }
+
+    fn assert_reconstructs<const R: usize, const C: usize>(m: &Matrix<R, C, f64>) {
+        let svd = m.svd().unwrap();
+        assert_orthogonal(&svd.u);
+        assert_orthogonal(&svd.vt);
+        let rebuilt = svd.u.product(&svd.sigma).unwrap().product(&svd.vt).unwrap();
+        for row in 0..R {
+            for col in 0..C {
+                assert_relative_eq!(rebuilt.data[row][col], m.data[row][col], epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_svd_square() {
+        let m = Matrix::<2, 2, f64>::new([[3.0, 0.0], [4.0, 5.0]]);
+        let svd = m.svd().unwrap();
+        let values = svd.singular_values();
+        assert_relative_eq!(values[0], 45f64.sqrt(), epsilon = 1e-12);
+        assert_relative_eq!(values[1], 5f64.sqrt(), epsilon = 1e-12);
+        assert_reconstructs(&m);
+    }
+
+    #[test]
+    fn test_svd_wide_and_tall() {
+        let wide = Matrix::<3, 4, f64>::new([
+            [1.0, 0.0, 0.0, 0.577],
+            [0.0, 1.0, 0.0, 0.577],
+            [0.0, 0.0, 1.0, 0.577],
+        ]);
+        assert_reconstructs(&wide);
+        assert_reconstructs(&wide.T());
+        assert_eq!(wide.svd().unwrap().singular_values().len(), 3);
+    }
+
+    #[test]
+    fn test_svd_rank_deficient() {
+        let m = Matrix::<3, 3, f64>::new([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 1.0, 1.0]]);
+        assert_reconstructs(&m);
+        assert_eq!(m.rank(1e-10).unwrap(), 2);
+        assert!(m.condition_number().unwrap() > 1e14);
+    }
+
+    #[test]
+    fn test_pinv_wide() {
+        let wide = Matrix::<3, 4, f64>::new([
+            [1.0, 0.0, 0.0, 0.577],
+            [0.0, 1.0, 0.0, 0.577],
+            [0.0, 0.0, 1.0, 0.577],
+        ]);
+        let pinv = wide.pinv().unwrap();
+        assert_eq!(pinv.size(), (4, 3));
+        let identity = wide.product(&pinv).unwrap();
+        for row in 0..3 {
+            for col in 0..3 {
+                let expected = if row == col { 1.0 } else { 0.0 };
+                assert_relative_eq!(identity.data[row][col], expected, epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pinv_matches_inverse() {
+        let m = Matrix::<2, 2, f64>::new([[4.0, 7.0], [2.0, 6.0]]);
+        let pinv = m.pinv().unwrap();
+        let inv = m.inverse().unwrap();
+        for row in 0..2 {
+            for col in 0..2 {
+                assert_relative_eq!(pinv.data[row][col], inv.data[row][col], epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_svd_tall() {
+        let mut data = [[0.0; 2]; 64];
+        for (i, row) in data.iter_mut().enumerate() {
+            let x = i as f64 / 8.0;
+            *row = [1.0, x];
+        }
+        let m = Matrix::<64, 2, f64>::new(data);
+        let start = std::time::Instant::now();
+        assert_reconstructs(&m);
+        assert!(start.elapsed() < std::time::Duration::from_secs(1));
+
+        let values = m.singular_values().unwrap();
+        assert_eq!(values.len(), 2);
+        let svd_values = m.svd().unwrap().singular_values();
+        assert_relative_eq!(values[0], svd_values[0], epsilon = 1e-10);
+        assert_relative_eq!(values[1], svd_values[1], epsilon = 1e-10);
+        assert_eq!(m.rank(1e-10).unwrap(), 2);
+
+        // 最小二乘：伪逆左乘得到单位阵
+        let identity = m.pinv().unwrap().product(&m).unwrap();
+        for row in 0..2 {
+            for col in 0..2 {
+                let expected = if row == col { 1.0 } else { 0.0 };
+                assert_relative_eq!(identity.data[row][col], expected, epsilon = 1e-10);
+            }
+        }
+
+        let column = Matrix::<200, 1, f64>::new([[1.0]; 200]);
+        assert_eq!(column.rank(1e-10).unwrap(), 1);
+        assert_relative_eq!(column.condition_number().unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_condition_number() {
+        let m = Matrix::<2, 2, f64>::new([[2.0, 0.0], [0.0, 0.5]]);
+        assert_relative_eq!(m.condition_number().unwrap(), 4.0, epsilon = 1e-12);
+        let zero = Matrix::<2, 2, f64>::new([[0.0, 0.0], [0.0, 0.0]]);
+        assert!(zero.condition_number().unwrap().is_infinite());
+        assert_eq!(zero.rank(1e-10).unwrap(), 0);
+        assert_reconstructs(&zero);
+    }
+}
@@ -2,47 +2,83 @@ use crate::dense::error;
 use std::convert::From;
 use std::fmt::Display;
 use std::ops::{Add, Mul, Sub};
-use std::sync::{mpsc, Arc};
-use std::thread;
-
-pub struct CalculateResult<T>
-where
-    T: Copy + Send + Sync + Default,
-{
-    pub x: usize,
-    pub y: usize,
-    pub value: T,
-}
 
+// f64: From<T> 保证转换不会失败
 pub fn convert_to_f64<T>(value: T) -> Result<f64, error::OperationError>
 where
     T: std::convert::Into<f64>,
     f64: From<T>,
 {
-    let result = value.try_into();
-    match result {
-        Ok(value) => Ok(value),
-        Err(_) => {
-            return Result::Err(error::OperationError {
-                message: "value error".to_string(),
-            });
-        }
-    }
+    Ok(f64::from(value))
 }
 
-pub fn print_single_line<const ROWS: usize, const COLS: usize, T>(data: &[[T;COLS];ROWS], digits: u8) -> String
+pub fn print_single_line<T>(data: &[T], digits: u8) -> String
 where
     T: Display,
 {
+    let cells: Vec<String> = data.iter().map(|value| value.to_string()).collect();
+    single_line(&cells, digits as usize, false)
+}
+
+fn single_line(cells: &[String], width: usize, right_align: bool) -> String {
     let mut line = String::from("[");
-    for row in 0..ROWS {
-        for col in 0..COLS {
-            let str = format!("{:<digit$}", data[row][col], digit = (digits + 2) as usize);
-            line.push_str(&str);
-        }
+    for cell in cells {
+        line.push_str(&pad_cell(cell, width + 2, right_align));
     }
     line.push_str("]\n");
-    line.to_string()
+    line
+}
+
+fn pad_cell(cell: &str, width: usize, right_align: bool) -> String {
+    if right_align {
+        format!("{:>width$}", cell, width = width)
+    } else {
+        format!("{:<width$}", cell, width = width)
+    }
+}
+
+// 网格输出的排版选项
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GridLayout {
+    // 每个单元格的最小宽度
+    pub width: usize,
+    pub right_align: bool,
+    // 单行输出 [[a, b], [c, d]]
+    pub compact: bool,
+}
+
+// 排版已经格式化好的单元格文本
+pub fn render_grid(cells: &[String], rows: usize, cols: usize, layout: &GridLayout) -> String {
+    if layout.compact {
+        let lines: Vec<String> = (0..rows)
+            .map(|row| format!("[{}]", cells[row * cols..(row + 1) * cols].join(", ")))
+            .collect();
+        return format!("[{}]", lines.join(", "));
+    }
+    let mut width = layout.width;
+    for cell in cells.iter() {
+        if cell.len() > width {
+            width = cell.len()
+        }
+    }
+    if rows == 0 || cols == 0 {
+        return "┌┐\n└┘\n".to_string();
+    } else if rows == 1 {
+        return single_line(cells, width, layout.right_align);
+    }
+    let mut result = String::new();
+    for row in 0..rows {
+        let mut line = String::new();
+        let (start_char, end_char) = get_boundary_char(row, rows);
+        line.push_str(&start_char);
+        for cell in &cells[row * cols..(row + 1) * cols] {
+            line.push_str(&pad_cell(cell, width + 2, layout.right_align));
+        }
+        line.push_str(&end_char);
+        line.push('\n');
+        result.push_str(&line);
+    }
+    result
 }
 
 pub fn get_boundary_char(row: usize, height: usize) -> (String, String) {
@@ -57,58 +93,39 @@ pub fn get_boundary_char(row: usize, height: usize) -> (String, String) {
     }
 }
 
-pub fn calculate_in_threads<'a, const ROWS: usize, const COLS: usize, T, F>(
-    a_data: &'a [[T;COLS];ROWS],
-    b_data: &'a [[T;COLS];ROWS],
-    func: F,
-) -> Result<[[T;COLS];ROWS], error::OperationError>
-where
-    T: Copy + Send + Sync + Default,
-    F: Fn(T, T) -> T + Send + Sync + Copy,
+// 矩阵乘法分块大小，保证每个块的数据能留在 L1/L2 缓存中
+pub const PRODUCT_BLOCK: usize = 64;
+// 乘法运算量（ROWS·COLS·COLS2）低于该值时并行路径退化为串行
+pub const PARALLEL_THRESHOLD: usize = 64 * 64 * 64;
+// 逐元素运算的元素个数低于该值时并行路径退化为串行
+pub const PARALLEL_ELEMENT_THRESHOLD: usize = 128 * 128;
+// 并行计算时每个任务处理的行数
+pub const PARALLEL_ROW_CHUNK: usize = 16;
+
+// 分块乘法内核：out += a_rows · b，a_rows 与 out 为结果中连续的若干行
+// 按 k、j 分块并保持 k 递增的累加顺序，结果与朴素三重循环逐位一致
+pub fn product_rows<const COLS: usize, const COLS2: usize, T>(
+    a_rows: &[[T; COLS]],
+    b: &[[T; COLS2]; COLS],
+    out: &mut [[T; COLS2]],
+) where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
 {
-    let mut result = [[T::default(); COLS]; ROWS];
-    thread::scope(|scope| {
-        let a_vec: Arc<&'a [[T;COLS];ROWS]> = Arc::new(a_data);
-        let b_vec: Arc<&'a [[T;COLS];ROWS]> = Arc::new(b_data);
-        let (sender, receiver) = mpsc::channel::<CalculateResult<T>>();
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                let self_data = a_vec.clone();
-                let other_data = b_vec.clone();
-                let s = sender.clone();
-                scope.spawn(move || {
-                    let value = func(self_data[row][col], other_data[row][col]);
-                    s.send(CalculateResult { x: col, y: row, value })
-                });
+    for kk in (0..COLS).step_by(PRODUCT_BLOCK) {
+        let k_end = (kk + PRODUCT_BLOCK).min(COLS);
+        for jj in (0..COLS2).step_by(PRODUCT_BLOCK) {
+            let j_end = (jj + PRODUCT_BLOCK).min(COLS2);
+            for (a_row, out_row) in a_rows.iter().zip(out.iter_mut()) {
+                for k in kk..k_end {
+                    let a = a_row[k];
+                    let b_row = &b[k][jj..j_end];
+                    for (cell, &b_value) in out_row[jj..j_end].iter_mut().zip(b_row) {
+                        *cell = *cell + a * b_value;
+                    }
+                }
             }
         }
-        drop(sender);
-        for rv in receiver {
-            result[rv.y][rv.x] = rv.value;
-        }
-    });
-    Result::Ok(result)
-}
-
-pub fn calculate_multi<const A_ROWS: usize, const A_COLS: usize, const B_COLS: usize, T>(
-    a: &[[T;A_COLS];A_ROWS],
-    b: &[[T;B_COLS];A_COLS],  // Assuming matrix multiplication dimensions
-    cur_row: usize,
-    cur_col: usize,
-) -> Result<CalculateResult<T>, error::OperationError>
-where
-    T: Copy + Display + Default + Send + Sync + Mul<Output = T> + Add<Output = T>,
-{
-    let mut sum: T = T::default();
-    for i in 0..A_COLS {
-        let value = a[cur_row][i] * b[i][cur_col];
-        sum = sum + value;
     }
-    Result::Ok(CalculateResult {
-        x: cur_col,
-        y: cur_row,
-        value: sum,
-    })
 }
 
 pub fn determinant_in_one_permutation<const N: usize, T>(
@@ -164,8 +181,9 @@ fn fill_in_permutation(
     level: u32,
 ) -> Result<(), error::OperationError> {
     if available.len() != flags.len() {
-        return Result::Err(error::OperationError {
-            message: "flags length does not match available`s length".to_string(),
+        return Result::Err(error::OperationError::LengthMismatch {
+            expected: available.len(),
+            found: flags.len(),
         });
     }
     if level as usize >= available.len() {
@@ -228,7 +246,7 @@ mod test {
     #[test]
     fn test_print_single_line() {
         let a: [[i32;5];1] = [[1, 2, 3, 4, 15]];
-        let result = print_single_line(&a, 1);
+        let result = print_single_line(a.as_flattened(), 1);
         println!("{}", result.clone());
         assert_eq!("[1  2  3  4  15 ]\n", result);
     }
@@ -249,32 +267,6 @@ mod test {
         assert_eq!("┘", end);
     }
 
-    #[test]
-    fn test_calculate_in_threads() {
-        let a: [[i32;3];2] = [[1, 2, 3], [4, 15, 18]];
-        let b: [[i32;3];2] = [[2, 4, 6], [8, 10, 22]];
-        let result = calculate_in_threads(&a, &b, |a, b| -> i32 {
-            return a + b;
-        });
-        assert!(result.is_ok());
-        if let Ok(arr) = result {
-            assert_eq!(arr, [[3, 6, 9], [12, 25, 40]]);
-        }
-    }
-
-    #[test]
-    fn test_calculate_multi() {
-        let a: [[i32;3];2] = [[1, 2, 3], [4, 5, 6]];
-        let b: [[i32;4];3] = [[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
-        let result = calculate_multi(&a, &b, 0, 1);
-        assert!(result.is_ok());
-        if let Ok(res) = result {
-            assert_eq!(res.x, 1);
-            assert_eq!(res.y, 0);
-            assert_eq!(res.value, 44);
-        }
-    }
-
     #[test]
     fn test_fill_in_permutation() {
         let a: Vec<u64> = vec![1, 2, 3, 4];
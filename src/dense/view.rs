@@ -0,0 +1,312 @@
+use crate::dense::error::OperationError;
+use crate::dense::Matrix;
+use array_init::array_init;
+
+// 矩阵中从 (r0, c0) 开始的 R×C 只读子块视图
+#[derive(Clone, Copy, Debug)]
+pub struct MatrixView<'a, const R: usize, const C: usize, const ROWS: usize, const COLS: usize, T>
+where
+    T: Copy + Send + Sync,
+{
+    data: &'a [[T; COLS]; ROWS],
+    r0: usize,
+    c0: usize,
+}
+
+// 矩阵中从 (r0, c0) 开始的 R×C 可写子块视图
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, const R: usize, const C: usize, const ROWS: usize, const COLS: usize, T>
+where
+    T: Copy + Send + Sync,
+{
+    data: &'a mut [[T; COLS]; ROWS],
+    r0: usize,
+    c0: usize,
+}
+
+fn check_block(
+    rows: usize,
+    cols: usize,
+    r0: usize,
+    c0: usize,
+    r: usize,
+    c: usize,
+) -> Result<(), OperationError> {
+    // 写成减法避免 r0 + r 溢出
+    if r0 > rows || r > rows - r0 || c0 > cols || c > cols - c0 {
+        return Err(OperationError::BlockOutOfBounds {
+            start: (r0, c0),
+            size: (r, c),
+            shape: (rows, cols),
+        });
+    }
+    Ok(())
+}
+
+impl<'a, const R: usize, const C: usize, const ROWS: usize, const COLS: usize, T>
+    MatrixView<'a, R, C, ROWS, COLS, T>
+where
+    T: Copy + Send + Sync,
+{
+    // 按 (行, 列) 读取子块内的元素
+    pub fn get(&self, row: usize, col: usize) -> Option<T> {
+        if row >= R || col >= C {
+            return None;
+        }
+        Some(self.data[self.r0 + row][self.c0 + col])
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (R, C)
+    }
+
+    // 复制为独立的矩阵
+    pub fn to_matrix(&self) -> Matrix<R, C, T> {
+        let data = array_init(|row| array_init(|col| self.data[self.r0 + row][self.c0 + col]));
+        Matrix { data, digits: 0 }
+    }
+}
+
+impl<'a, const R: usize, const C: usize, const ROWS: usize, const COLS: usize, T>
+    MatrixViewMut<'a, R, C, ROWS, COLS, T>
+where
+    T: Copy + Send + Sync,
+{
+    // 按 (行, 列) 读取子块内的元素
+    pub fn get(&self, row: usize, col: usize) -> Option<T> {
+        if row >= R || col >= C {
+            return None;
+        }
+        Some(self.data[self.r0 + row][self.c0 + col])
+    }
+
+    // 按 (行, 列) 写入子块内的元素
+    pub fn set(&mut self, row: usize, col: usize, value: T) -> Result<(), OperationError> {
+        if row >= R || col >= C {
+            return Err(OperationError::IndexOutOfBounds {
+                index: (row, col),
+                shape: (R, C),
+            });
+        }
+        self.data[self.r0 + row][self.c0 + col] = value;
+        Ok(())
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (R, C)
+    }
+
+    // 用同尺寸矩阵覆盖整个子块
+    pub fn copy_from(&mut self, source: &Matrix<R, C, T>) {
+        for (row, line) in source.data.iter().enumerate() {
+            self.data[self.r0 + row][self.c0..self.c0 + C].copy_from_slice(line);
+        }
+    }
+
+    // 对子块内每个元素原地变换
+    pub fn apply<F>(&mut self, mut func: F)
+    where
+        F: FnMut(T) -> T,
+    {
+        for row in self.r0..self.r0 + R {
+            for value in self.data[row][self.c0..self.c0 + C].iter_mut() {
+                *value = func(*value);
+            }
+        }
+    }
+
+    // 复制为独立的矩阵
+    pub fn to_matrix(&self) -> Matrix<R, C, T> {
+        let data = array_init(|row| array_init(|col| self.data[self.r0 + row][self.c0 + col]));
+        Matrix { data, digits: 0 }
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, T> Matrix<ROWS, COLS, T>
+where
+    T: Copy + Send + Sync,
+{
+    // 取第 i 行，返回行向量
+    pub fn row(&self, i: usize) -> Result<Matrix<1, COLS, T>, OperationError> {
+        if i >= ROWS {
+            return Err(OperationError::RowOutOfBounds { row: i, rows: ROWS });
+        }
+        Ok(Matrix {
+            data: [self.data[i]],
+            digits: 0,
+        })
+    }
+
+    // 取第 j 列，返回列向量
+    pub fn col(&self, j: usize) -> Result<Matrix<ROWS, 1, T>, OperationError> {
+        if j >= COLS {
+            return Err(OperationError::ColumnOutOfBounds { col: j, cols: COLS });
+        }
+        let data = array_init(|row| [self.data[row][j]]);
+        Ok(Matrix { data, digits: 0 })
+    }
+
+    pub fn set_row(&mut self, i: usize, value: &Matrix<1, COLS, T>) -> Result<(), OperationError> {
+        if i >= ROWS {
+            return Err(OperationError::RowOutOfBounds { row: i, rows: ROWS });
+        }
+        self.data[i] = value.data[0];
+        Ok(())
+    }
+
+    pub fn set_col(&mut self, j: usize, value: &Matrix<ROWS, 1, T>) -> Result<(), OperationError> {
+        if j >= COLS {
+            return Err(OperationError::ColumnOutOfBounds { col: j, cols: COLS });
+        }
+        for (row, line) in self.data.iter_mut().enumerate() {
+            line[j] = value.data[row][0];
+        }
+        Ok(())
+    }
+
+    // 从 (r0, c0) 开始的 R×C 只读视图
+    pub fn fixed_view<const R: usize, const C: usize>(
+        &self,
+        r0: usize,
+        c0: usize,
+    ) -> Result<MatrixView<'_, R, C, ROWS, COLS, T>, OperationError> {
+        check_block(ROWS, COLS, r0, c0, R, C)?;
+        Ok(MatrixView {
+            data: &self.data,
+            r0,
+            c0,
+        })
+    }
+
+    // 从 (r0, c0) 开始的 R×C 可写视图
+    pub fn fixed_view_mut<const R: usize, const C: usize>(
+        &mut self,
+        r0: usize,
+        c0: usize,
+    ) -> Result<MatrixViewMut<'_, R, C, ROWS, COLS, T>, OperationError> {
+        check_block(ROWS, COLS, r0, c0, R, C)?;
+        Ok(MatrixViewMut {
+            data: &mut self.data,
+            r0,
+            c0,
+        })
+    }
+
+    // 复制从 (r0, c0) 开始的 R×C 子块
+    pub fn block<const R: usize, const C: usize>(
+        &self,
+        r0: usize,
+        c0: usize,
+    ) -> Result<Matrix<R, C, T>, OperationError> {
+        Ok(self.fixed_view::<R, C>(r0, c0)?.to_matrix())
+    }
+
+    // 用 block 覆盖从 (r0, c0) 开始的子块
+    pub fn set_block<const R: usize, const C: usize>(
+        &mut self,
+        r0: usize,
+        c0: usize,
+        block: &Matrix<R, C, T>,
+    ) -> Result<(), OperationError> {
+        self.fixed_view_mut::<R, C>(r0, c0)?.copy_from(block);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_row_and_col() {
+        let m = Matrix::<2, 3, i32>::new([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(m.row(1).unwrap().data, [[4, 5, 6]]);
+        assert_eq!(m.col(2).unwrap().data, [[3], [6]]);
+        assert_eq!(m.row(2).unwrap_err(), OperationError::RowOutOfBounds { row: 2, rows: 2 });
+        assert_eq!(m.col(3).unwrap_err(), OperationError::ColumnOutOfBounds { col: 3, cols: 3 });
+    }
+
+    #[test]
+    fn test_set_row_and_col() {
+        let mut m = Matrix::<2, 3, i32>::new([[1, 2, 3], [4, 5, 6]]);
+        m.set_row(0, &Matrix::new([[7, 8, 9]])).unwrap();
+        m.set_col(1, &Matrix::new([[0], [0]])).unwrap();
+        assert_eq!(m.data, [[7, 0, 9], [4, 0, 6]]);
+        assert!(m.set_row(2, &Matrix::new([[0, 0, 0]])).is_err());
+        assert!(m.set_col(3, &Matrix::new([[0], [0]])).is_err());
+    }
+
+    #[test]
+    fn test_fixed_view() {
+        let m = Matrix::<3, 3, i32>::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let view = m.fixed_view::<2, 2>(1, 1).unwrap();
+        assert_eq!(view.size(), (2, 2));
+        assert_eq!(view.get(0, 1), Some(6));
+        assert_eq!(view.get(2, 0), None);
+        assert_eq!(view.to_matrix().data, [[5, 6], [8, 9]]);
+        assert_eq!(m.block::<1, 3>(2, 0).unwrap().data, [[7, 8, 9]]);
+
+        let err = m.fixed_view::<2, 2>(2, 0).unwrap_err();
+        assert_eq!(
+            err,
+            OperationError::BlockOutOfBounds {
+                start: (2, 0),
+                size: (2, 2),
+                shape: (3, 3)
+            }
+        );
+    }
+
+    #[test]
+    fn test_block_bounds_do_not_overflow() {
+        let m = Matrix::<3, 3, i32>::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(
+            m.fixed_view::<1, 1>(usize::MAX, 0).unwrap_err(),
+            OperationError::BlockOutOfBounds {
+                start: (usize::MAX, 0),
+                size: (1, 1),
+                shape: (3, 3)
+            }
+        );
+        assert!(m.fixed_view::<1, 1>(0, usize::MAX).is_err());
+        let mut m = m;
+        assert!(m.fixed_view_mut::<2, 1>(usize::MAX - 1, 0).is_err());
+    }
+
+    #[test]
+    fn test_empty_blocks() {
+        let m = Matrix::<3, 3, i32>::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(m.fixed_view::<0, 0>(3, 3).unwrap().to_matrix().size(), (0, 0));
+        assert_eq!(m.block::<0, 2>(1, 1).unwrap().size(), (0, 2));
+        assert_eq!(m.block::<2, 0>(1, 3).unwrap().size(), (2, 0));
+
+        let mut empty = Matrix::<0, 2, i32>::new([]);
+        assert_eq!(empty.col(1).unwrap().size(), (0, 1));
+        let view = empty.fixed_view_mut::<0, 2>(0, 0).unwrap();
+        assert_eq!(view.to_matrix().size(), (0, 2));
+    }
+
+    #[test]
+    fn test_fixed_view_mut() {
+        let mut m = Matrix::<3, 3, i32>::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        {
+            let mut view = m.fixed_view_mut::<2, 2>(0, 1).unwrap();
+            view.set(1, 1, 0).unwrap();
+            assert!(view.set(2, 0, 0).is_err());
+            view.apply(|x| x * 10);
+            assert_eq!(view.get(0, 0), Some(20));
+        }
+        assert_eq!(m.data, [[1, 20, 30], [4, 50, 0], [7, 8, 9]]);
+        assert!(m.fixed_view_mut::<1, 1>(3, 0).is_err());
+    }
+
+    #[test]
+    fn test_set_block() {
+        let mut p = Matrix::<6, 6, f64>::zeros();
+        let q = Matrix::<3, 3, f64>::unit().scale(0.5);
+        p.set_block(3, 3, &q).unwrap();
+        assert_eq!(p.data[4], [0.0, 0.0, 0.0, 0.0, 0.5, 0.0]);
+        assert_eq!(p.block::<3, 3>(3, 3).unwrap().data, q.data);
+        assert!(p.set_block(4, 4, &q).is_err());
+    }
+}
@@ -1,5 +1,6 @@
 
 pub mod dense;
+pub mod sparse;
 pub mod physics;
 pub mod constant;
 pub mod spatial_geometry;
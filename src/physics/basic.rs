@@ -9,20 +9,32 @@ mod angular_momentum;
 mod angular_velocity;
 mod area;
 mod coef;
+pub mod dimension;
 mod distance;
 mod energy;
 mod force;
+mod format;
+mod heat_capacity;
 mod magnetic_angular_velocity;
 mod magnetic_induction;
 mod magnetic_moment;
 pub mod mass;
 mod momentum;
+mod parse;
 mod power;
+mod temperature;
+mod time;
 mod torque;
+mod unit;
 pub mod vector;
 mod velocity;
 mod volume;
 
+pub use dimension::{Dim, Dimension, Dimensional, MomentOfInertia, Quantity};
+pub use format::{QuantityFormat, VectorFormat};
+pub use parse::ParseQuantityError;
+pub use unit::{UnitQuantity, UnitSymbol};
+
 //支持向量的物理量
 pub trait VectorQuantity: PhysicalQuantity {}
 
@@ -37,12 +49,14 @@ pub trait PhysicalQuantity: Any {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DistanceType {
     M,
     KM,
     LightYear,
 }
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VelocityType {
     MPerSecond,
     KmPerHour,
@@ -61,6 +75,7 @@ pub enum VelocityType {
 /// println!("距离: {} 米", distance.as_m());
 /// ```
 #[derive(Clone, Debug, PartialEq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Distance {
     default_type: DistanceType,
     v: f64,
@@ -77,6 +92,7 @@ pub struct Distance {
 /// println!("速度: {} m/s", velocity.as_m_per_sec());
 /// ```
 #[derive(Clone, Debug, PartialEq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Velocity {
     default_type: VelocityType,
     v: f64,
@@ -94,11 +110,13 @@ pub struct Velocity {
 /// println!("加速度: {} g", acceleration.as_g());
 /// ```
 #[derive(Clone, Debug, PartialEq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Acceleration {
     default_type: AccelerationType,
     v: f64,
 }
 #[derive(Clone, Debug, PartialEq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AccelerationType {
     MPerSecond2,
     KmPerHour2,
@@ -106,6 +124,7 @@ pub enum AccelerationType {
 }
 
 #[derive(Clone, Debug, PartialEq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AngularType {
     Rad,
     Deg,
@@ -123,11 +142,13 @@ pub enum AngularType {
 /// println!("角度: {} 弧度", angle.as_rad());
 /// ```
 #[derive(Clone, Debug, PartialEq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Angular {
     default_type: AngularType,
     v: f64,
 }
 #[derive(Clone, Debug, PartialEq, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AngularVelocityType {
     #[default]
     RadperSecond,
@@ -148,12 +169,14 @@ pub enum AngularVelocityType {
 /// println!("角速度: {} rad/s", angular_velocity.as_rad_per_second());
 /// ```
 #[derive(Clone, Debug, PartialEq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AngularVelocity {
     default_type: AngularVelocityType,
     v: f64,
 }
 
 #[derive(Clone, Debug, PartialEq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AngularAccelerationType {
     RadperSecond2,
     DegPerSecond2,
@@ -171,6 +194,7 @@ pub enum AngularAccelerationType {
 /// println!("角加速度: {} rad/s²", angular_acceleration.as_rad_per_second2());
 /// ```
 #[derive(Clone, Debug, PartialEq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AngularAcceleration {
     default_type: AngularAccelerationType,
     v: f64,
@@ -187,10 +211,12 @@ pub struct AngularAcceleration {
 /// println!("系数: {}", coefficient.get_value());
 /// ```
 #[derive(Clone, Debug, PartialEq, PartialOrd, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Coef {
     v: f64,
 }
 #[derive(Clone, Debug, PartialEq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MassType {
     Kg,
     g,
@@ -208,11 +234,13 @@ pub enum MassType {
 /// println!("质量: {} kg", mass.as_kg());
 /// ```
 #[derive(Clone, Debug, PartialEq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Mass {
     default_type: MassType,
     pub v: f64,
 }
 #[derive(Clone, Debug, PartialEq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AngularMomentumType {
     KgM2perSecond,  // 每秒1千克1平方米
     KgKm2perSecond, // 每秒1千克1平方公里
@@ -233,11 +261,13 @@ pub enum AngularMomentumType {
 /// println!("角动量: {} kg·m²/s", angular_momentum.as_kg_m2_per_second());
 /// ```
 #[derive(Clone, Debug, PartialEq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AngularMomentum {
     default_type: AngularMomentumType,
     pub v: f64,
 }
 #[derive(Clone, Debug, PartialEq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MomentumType {
     KgMperSecond,  // 每秒1千克1米
     KgKmperSecond, // 每秒1千克1千米
@@ -254,6 +284,7 @@ pub enum MomentumType {
 /// println!("动量: {} kg·m/s", momentum.as_kg_m_s());
 /// ```
 #[derive(Clone, Debug, PartialEq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Momentum {
     default_type: MomentumType,
     pub v: f64,
@@ -278,6 +309,7 @@ pub struct Momentum {
 /// println!("位置: ({}, {}, {})", position.x.as_m(), position.y.as_m(), position.z.as_m());
 /// ```
 #[derive(Clone, Debug, PartialEq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vector3<T: VectorQuantity + Default> {
     pub x: T,
     pub y: T,
@@ -285,6 +317,7 @@ pub struct Vector3<T: VectorQuantity + Default> {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AreaType {
     M2,
     KM2,
@@ -302,11 +335,13 @@ pub enum AreaType {
 /// println!("面积: {} m²", area.as_m2());
 /// ```
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Area {
     default_type: AreaType,
     pub v: f64,
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MagneticInductionType {
     Gauss,
     #[default]
@@ -329,12 +364,14 @@ pub enum MagneticInductionType {
 /// println!("磁感应强度: {} T", magnetic_field.as_tesla());
 /// ```
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MagneticInduction {
     default_type: MagneticInductionType,
     pub v: f64,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MagneticMomentType {
     AM2,            // 安培·平方米 (A·m²)
     MillAM2,        // 毫安培·平方米 (mA·m²)
@@ -358,12 +395,14 @@ pub enum MagneticMomentType {
 /// println!("磁矩: {} A·m²", magnetic_moment.as_am2());
 /// ```
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MagneticMoment {
     default_type: MagneticMomentType,
     pub v: f64,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TorqueType {
     NM,      // 牛顿·米 (N·m)
     MillNM,  // 毫牛顿·米 (mN·m)
@@ -385,12 +424,14 @@ pub enum TorqueType {
 /// println!("力矩: {} N·m", torque.as_nm());
 /// ```
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Torque {
     default_type: TorqueType,
     pub v: f64,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EnergyType {
     Joule,            // 焦耳 (J)
     MillJoule,        // 毫焦耳 (mJ)
@@ -415,12 +456,14 @@ pub enum EnergyType {
 /// println!("能量: {} J", energy.as_joule());
 /// ```
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Energy {
     default_type: EnergyType,
     pub v: f64,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ForceType {
     Newton,      // 牛顿 (N)
     MillNewton,  // 毫牛顿 (mN)
@@ -442,12 +485,14 @@ pub enum ForceType {
 /// println!("力: {} N", force.as_newton());
 /// ```
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Force {
     default_type: ForceType,
     pub v: f64,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PowerType {
     Watt,       // 瓦特 (W)
     MillWatt,   // 毫瓦特 (mW)
@@ -470,12 +515,14 @@ pub enum PowerType {
 /// println!("功率: {} W", power.as_watt());
 /// ```
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Power {
     default_type: PowerType,
     pub v: f64,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MagneticAngularVelocityType {
     TeslaRadPerSecond,      // 特斯拉·弧度/秒 (T·rad/s)
     MillTeslaRadPerSecond,  // 毫特斯拉·弧度/秒 (mT·rad/s)
@@ -498,12 +545,14 @@ pub enum MagneticAngularVelocityType {
 /// println!("磁角速度: {} T·rad/s", magnetic_angular_velocity.as_tesla_rad_per_second());
 /// ```
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MagneticAngularVelocity {
     default_type: MagneticAngularVelocityType,
     pub v: f64,
 }
 
 #[derive(Clone, Debug, PartialEq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VolumeType {
     M3,
     KM3,
@@ -520,11 +569,131 @@ pub enum VolumeType {
 /// println!("体积: {} m³", volume.as_m3());
 /// ```
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Volume {
     default_type: VolumeType,
     pub v: f64,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeType {
+    Second,      // 秒 (s)
+    MillSecond,  // 毫秒 (ms)
+    MicroSecond, // 微秒 (μs)
+    Minute,      // 分钟 (min)
+    Hour,        // 小时 (h)
+    Day,         // 天 (d)
+}
+
+/// 时间物理量结构体
+///
+/// 表示时间间隔，与 `std::time::Duration` 不同，可以为负数，也可以表示纳秒以下的步长
+///
+/// # 示例
+/// ```
+/// use zmatrix::physics::basic::{Time, Velocity};
+///
+/// let dt = Time::from_mill_second(-20.0);
+/// let distance = Velocity::from_m_per_sec(5.0) * dt;
+/// println!("位移: {} m", distance.as_m());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Time {
+    default_type: TimeType,
+    v: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TemperatureType {
+    Kelvin,     // 开尔文 (K)
+    Celsius,    // 摄氏度 (°C)
+    Fahrenheit, // 华氏度 (°F)
+}
+
+/// 温度物理量结构体
+///
+/// 表示绝对温度，摄氏度与华氏度带有零点偏移。两个温度相减得到 `TemperatureDifference`，
+/// 温度加减温差仍为温度，温度之间不能相加
+///
+/// # 示例
+/// ```
+/// use zmatrix::physics::basic::{Temperature, TemperatureDifference};
+///
+/// let t = Temperature::from_celsius(20.0) + TemperatureDifference::from_kelvin(5.0);
+/// println!("温度: {} °C", t.as_celsius());
+/// ```
+///
+/// 绝对温度不实现 `Dimensional`，不能进入 `Quantity` 的量纲运算，需要先相减得到温差：
+/// ```compile_fail
+/// use zmatrix::physics::basic::{Dimensional, Temperature};
+///
+/// let q = Temperature::from_celsius(20.0).quantity() + Temperature::from_celsius(20.0).quantity();
+/// ```
+/// ```compile_fail
+/// use zmatrix::physics::basic::{Dimensional, Energy, Temperature};
+///
+/// let c = Energy::from_joule(293.15).quantity() / Temperature::from_celsius(20.0);
+/// ```
+/// ```compile_fail
+/// use zmatrix::physics::basic::{Dimensional, Temperature, TemperatureDifference};
+///
+/// let t: Temperature = TemperatureDifference::from_celsius(5.0).quantity().into();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Temperature {
+    default_type: TemperatureType,
+    v: f64,
+}
+
+/// 温差物理量结构体
+///
+/// 表示温度的变化量，换算时不带零点偏移：1 °C 的温差等于 1 K，1 °F 的温差等于 5/9 K
+///
+/// # 示例
+/// ```
+/// use zmatrix::physics::basic::TemperatureDifference;
+///
+/// let dt = TemperatureDifference::from_fahrenheit(9.0);
+/// println!("温差: {} K", dt.as_kelvin());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TemperatureDifference {
+    default_type: TemperatureType,
+    v: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HeatCapacityType {
+    JoulePerKelvin,     // 焦耳/开尔文 (J/K)
+    MillJoulePerKelvin, // 毫焦耳/开尔文 (mJ/K)
+    KiloJoulePerKelvin, // 千焦耳/开尔文 (kJ/K)
+}
+
+/// 热容物理量结构体
+///
+/// 物体温度升高 1 K 所需的能量，与温差相乘得到能量
+///
+/// # 示例
+/// ```
+/// use zmatrix::physics::basic::{Energy, HeatCapacity, TemperatureDifference};
+///
+/// let c = HeatCapacity::from_joule_per_kelvin(900.0);
+/// let q: Energy = c * TemperatureDifference::from_celsius(10.0);
+/// println!("热量: {} J", q.as_joule());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HeatCapacity {
+    default_type: HeatCapacityType,
+    v: f64,
+}
+
 impl Default for Distance {
     fn default() -> Self {
         Distance::from_m(0.0)
@@ -758,6 +927,33 @@ mod tests {
             assert_relative_eq!(a.default_unit_value(), a.as_rad(), epsilon = 1e-12);
         }
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let d = Distance::from_km(1.5);
+        let json = serde_json::to_string(&d).unwrap();
+        assert_eq!(json, r#"{"default_type":"KM","v":1.5}"#);
+        assert_eq!(serde_json::from_str::<Distance>(&json).unwrap(), d);
+
+        let e = Energy::from_kilo_electron_volt(2.0);
+        let back: Energy = serde_json::from_str(&serde_json::to_string(&e).unwrap()).unwrap();
+        assert_eq!(back, e);
+        assert_relative_eq!(back.as_kilo_electron_volt(), 2.0);
+
+        let m = Mass::from_g(250.0);
+        assert_eq!(serde_json::from_str::<Mass>(&serde_json::to_string(&m).unwrap()).unwrap(), m);
+        let c = Coef::new(0.5);
+        assert_eq!(serde_json::to_string(&c).unwrap(), r#"{"v":0.5}"#);
+
+        let v = Vector3::new(
+            Velocity::from_km_per_h(1.0),
+            Velocity::from_m_per_sec(2.0),
+            Velocity::from_light_speed(0.1),
+        );
+        let back: Vector3<Velocity> = serde_json::from_str(&serde_json::to_string(&v).unwrap()).unwrap();
+        assert_eq!(back, v);
+    }
 }
 
 // 手动实现 VectorQuantity trait 给所有支持向量的物理量
@@ -0,0 +1,612 @@
+use crate::physics::basic::{
+    Acceleration, Angular, AngularAcceleration, AngularMomentum, AngularVelocity, Area, Coef,
+    Distance, Energy, Force, HeatCapacity, MagneticAngularVelocity, MagneticInduction,
+    MagneticMoment, Mass, Momentum, PhysicalQuantity, Power, TemperatureDifference, Time, Torque,
+    Velocity, Volume,
+};
+use std::any::Any;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use typenum::{Diff, Integer, Prod, Quot, Sum, N1, N2, N3, P1, P2, P3, Z0};
+
+/// 国际单位制量纲
+///
+/// 类型参数依次为长度 L、质量 M、时间 T、电流 I、热力学温度 Θ、物质的量 N、发光强度 J 的指数，
+/// 指数用 `typenum` 的类型级整数表示，量纲相乘时指数相加、相除时指数相减。
+/// 弧度是无量纲的，因此 `Torque` 与 `Energy` 的量纲相同（N·m = J）。
+pub struct Dim<L, M, T, I, Th, N, J>(PhantomData<(L, M, T, I, Th, N, J)>);
+
+pub trait Dimension: 'static {
+    // [L, M, T, I, Θ, N, J] 的指数
+    const EXPONENTS: [i32; 7];
+}
+
+impl<L, M, T, I, Th, N, J> Dimension for Dim<L, M, T, I, Th, N, J>
+where
+    L: Integer + 'static,
+    M: Integer + 'static,
+    T: Integer + 'static,
+    I: Integer + 'static,
+    Th: Integer + 'static,
+    N: Integer + 'static,
+    J: Integer + 'static,
+{
+    const EXPONENTS: [i32; 7] = [L::I32, M::I32, T::I32, I::I32, Th::I32, N::I32, J::I32];
+}
+
+// 量纲相乘：指数逐项相加
+impl<L1, M1, T1, I1, Th1, N1_, J1, L2, M2, T2, I2, Th2, N2_, J2>
+    Mul<Dim<L2, M2, T2, I2, Th2, N2_, J2>> for Dim<L1, M1, T1, I1, Th1, N1_, J1>
+where
+    L1: Add<L2>,
+    M1: Add<M2>,
+    T1: Add<T2>,
+    I1: Add<I2>,
+    Th1: Add<Th2>,
+    N1_: Add<N2_>,
+    J1: Add<J2>,
+{
+    type Output = Dim<
+        Sum<L1, L2>,
+        Sum<M1, M2>,
+        Sum<T1, T2>,
+        Sum<I1, I2>,
+        Sum<Th1, Th2>,
+        Sum<N1_, N2_>,
+        Sum<J1, J2>,
+    >;
+
+    fn mul(self, _: Dim<L2, M2, T2, I2, Th2, N2_, J2>) -> Self::Output {
+        Dim(PhantomData)
+    }
+}
+
+// 量纲相除：指数逐项相减
+impl<L1, M1, T1, I1, Th1, N1_, J1, L2, M2, T2, I2, Th2, N2_, J2>
+    Div<Dim<L2, M2, T2, I2, Th2, N2_, J2>> for Dim<L1, M1, T1, I1, Th1, N1_, J1>
+where
+    L1: Sub<L2>,
+    M1: Sub<M2>,
+    T1: Sub<T2>,
+    I1: Sub<I2>,
+    Th1: Sub<Th2>,
+    N1_: Sub<N2_>,
+    J1: Sub<J2>,
+{
+    type Output = Dim<
+        Diff<L1, L2>,
+        Diff<M1, M2>,
+        Diff<T1, T2>,
+        Diff<I1, I2>,
+        Diff<Th1, Th2>,
+        Diff<N1_, N2_>,
+        Diff<J1, J2>,
+    >;
+
+    fn div(self, _: Dim<L2, M2, T2, I2, Th2, N2_, J2>) -> Self::Output {
+        Dim(PhantomData)
+    }
+}
+
+pub type DimensionlessDim = Dim<Z0, Z0, Z0, Z0, Z0, Z0, Z0>;
+pub type LengthDim = Dim<P1, Z0, Z0, Z0, Z0, Z0, Z0>;
+pub type MassDim = Dim<Z0, P1, Z0, Z0, Z0, Z0, Z0>;
+pub type TimeDim = Dim<Z0, Z0, P1, Z0, Z0, Z0, Z0>;
+pub type CurrentDim = Dim<Z0, Z0, Z0, P1, Z0, Z0, Z0>;
+pub type TemperatureDim = Dim<Z0, Z0, Z0, Z0, P1, Z0, Z0>;
+pub type AreaDim = Dim<P2, Z0, Z0, Z0, Z0, Z0, Z0>;
+pub type VolumeDim = Dim<P3, Z0, Z0, Z0, Z0, Z0, Z0>;
+pub type VelocityDim = Dim<P1, Z0, N1, Z0, Z0, Z0, Z0>;
+pub type AccelerationDim = Dim<P1, Z0, N2, Z0, Z0, Z0, Z0>;
+// 弧度无量纲，角速度与频率同为 s⁻¹
+pub type AngularVelocityDim = Dim<Z0, Z0, N1, Z0, Z0, Z0, Z0>;
+pub type AngularAccelerationDim = Dim<Z0, Z0, N2, Z0, Z0, Z0, Z0>;
+pub type MomentumDim = Dim<P1, P1, N1, Z0, Z0, Z0, Z0>;
+pub type AngularMomentumDim = Dim<P2, P1, N1, Z0, Z0, Z0, Z0>;
+pub type MomentOfInertiaDim = Dim<P2, P1, Z0, Z0, Z0, Z0, Z0>;
+pub type ForceDim = Dim<P1, P1, N2, Z0, Z0, Z0, Z0>;
+// 能量与力矩同为 kg·m²·s⁻²
+pub type EnergyDim = Dim<P2, P1, N2, Z0, Z0, Z0, Z0>;
+pub type TorqueDim = EnergyDim;
+pub type PowerDim = Dim<P2, P1, N3, Z0, Z0, Z0, Z0>;
+pub type MagneticInductionDim = Dim<Z0, P1, N2, N1, Z0, Z0, Z0>;
+pub type MagneticMomentDim = Dim<P2, Z0, Z0, P1, Z0, Z0, Z0>;
+pub type MagneticAngularVelocityDim = Dim<Z0, P1, N3, N1, Z0, Z0, Z0>;
+pub type HeatCapacityDim = Dim<P2, P1, N2, Z0, N1, Z0, Z0>;
+
+/// 带量纲的物理量，数值以国际单位制基本单位存放
+///
+/// 任意两个物理量相乘或相除时，结果的量纲由类型系统自动推导，量纲不一致的加减无法通过编译。
+///
+/// # 示例
+/// ```
+/// use zmatrix::physics::basic::{Angular, Energy, Mass, Area, Torque, MomentOfInertia};
+///
+/// let work: Energy = (Torque::from_nm(2.0) * Angular::from_rad(3.0)).into();
+/// assert_eq!(work.as_joule(), 6.0);
+///
+/// let inertia: MomentOfInertia = Mass::from_kg(2.0) * Area::from_m2(0.5);
+/// assert_eq!(inertia.value(), 1.0);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
+pub struct Quantity<D> {
+    v: f64,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    dimension: PhantomData<D>,
+}
+
+// 转动惯量 kg·m²
+pub type MomentOfInertia = Quantity<MomentOfInertiaDim>;
+
+impl<D> Quantity<D> {
+    // 由国际单位制数值构造
+    pub fn new(v: f64) -> Self {
+        Quantity {
+            v,
+            dimension: PhantomData,
+        }
+    }
+
+    // 国际单位制下的数值
+    pub fn value(&self) -> f64 {
+        self.v
+    }
+}
+
+impl<D: Dimension> Quantity<D> {
+    // [L, M, T, I, Θ, N, J] 的指数
+    pub fn exponents(&self) -> [i32; 7] {
+        D::EXPONENTS
+    }
+}
+
+impl<D> Clone for Quantity<D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<D> Copy for Quantity<D> {}
+
+impl<D> Default for Quantity<D> {
+    fn default() -> Self {
+        Quantity::new(0.0)
+    }
+}
+
+impl<D> PartialEq for Quantity<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.v == other.v
+    }
+}
+
+impl<D> PartialOrd for Quantity<D> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.v.partial_cmp(&other.v)
+    }
+}
+
+impl<D: Dimension> Debug for Quantity<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Quantity")
+            .field("v", &self.v)
+            .field("exponents", &D::EXPONENTS)
+            .finish()
+    }
+}
+
+impl<D: Dimension> PhysicalQuantity for Quantity<D> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_zero(&self) -> bool {
+        self.v == 0.0
+    }
+
+    fn default_unit_value(&self) -> f64 {
+        self.v
+    }
+
+    fn set_value(&mut self, value: f64) {
+        self.v = value;
+    }
+}
+
+/// 可以转换为 [`Quantity`] 的物理量
+///
+/// 具名物理量（`Torque`、`Energy` 等）仍然保存自己的单位，通过 `quantity()` 进入量纲运算，
+/// 再通过 `From<Quantity<_>>` 转回国际单位制下的具名物理量。
+pub trait Dimensional: PhysicalQuantity + Copy {
+    type Dimension: Dimension;
+
+    // 由国际单位制数值构造
+    fn from_si_value(v: f64) -> Self;
+
+    fn quantity(&self) -> Quantity<Self::Dimension> {
+        Quantity::new(self.default_unit_value())
+    }
+}
+
+impl<D: Dimension> Dimensional for Quantity<D> {
+    type Dimension = D;
+
+    fn from_si_value(v: f64) -> Self {
+        Quantity::new(v)
+    }
+}
+
+impl<D> Add for Quantity<D> {
+    type Output = Quantity<D>;
+    fn add(self, rhs: Self) -> Self::Output {
+        Quantity::new(self.v + rhs.v)
+    }
+}
+
+impl<D> Sub for Quantity<D> {
+    type Output = Quantity<D>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Quantity::new(self.v - rhs.v)
+    }
+}
+
+impl<D> Neg for Quantity<D> {
+    type Output = Quantity<D>;
+    fn neg(self) -> Self::Output {
+        Quantity::new(-self.v)
+    }
+}
+
+impl<D> Mul<f64> for Quantity<D> {
+    type Output = Quantity<D>;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Quantity::new(self.v * rhs)
+    }
+}
+
+impl<D> Div<f64> for Quantity<D> {
+    type Output = Quantity<D>;
+    fn div(self, rhs: f64) -> Self::Output {
+        Quantity::new(self.v / rhs)
+    }
+}
+
+impl<D> Mul<Quantity<D>> for f64 {
+    type Output = Quantity<D>;
+    fn mul(self, rhs: Quantity<D>) -> Self::Output {
+        Quantity::new(self * rhs.v)
+    }
+}
+
+// 与任意 Quantity 或具名物理量相乘，结果量纲为两者之积
+impl<D, R> Mul<R> for Quantity<D>
+where
+    R: Dimensional,
+    D: Mul<R::Dimension>,
+{
+    type Output = Quantity<<D as Mul<R::Dimension>>::Output>;
+    fn mul(self, rhs: R) -> Self::Output {
+        Quantity::new(self.v * rhs.default_unit_value())
+    }
+}
+
+impl<D, R> Div<R> for Quantity<D>
+where
+    R: Dimensional,
+    D: Div<R::Dimension>,
+{
+    type Output = Quantity<<D as Div<R::Dimension>>::Output>;
+    fn div(self, rhs: R) -> Self::Output {
+        Quantity::new(self.v / rhs.default_unit_value())
+    }
+}
+
+// 具名物理量与 Quantity 互转，from_si 为该物理量的国际单位制构造函数
+macro_rules! impl_dimensional {
+    ($($name:ident: $dim:ty => $from_si:expr;)*) => {
+        $(
+            impl Dimensional for $name {
+                type Dimension = $dim;
+
+                fn from_si_value(v: f64) -> Self {
+                    $from_si(v)
+                }
+            }
+
+            impl From<$name> for Quantity<$dim> {
+                fn from(value: $name) -> Self {
+                    value.quantity()
+                }
+            }
+
+            impl From<Quantity<$dim>> for $name {
+                fn from(value: Quantity<$dim>) -> Self {
+                    $name::from_si_value(value.value())
+                }
+            }
+        )*
+    };
+}
+
+impl_dimensional! {
+    Coef: DimensionlessDim => Coef::new;
+    Angular: DimensionlessDim => Angular::from_rad;
+    Distance: LengthDim => Distance::from_m;
+    Mass: MassDim => Mass::from_kg;
+    Area: AreaDim => Area::from_m2;
+    Volume: VolumeDim => Volume::from_m3;
+    Time: TimeDim => Time::from_second;
+    // 绝对温度带零点偏移，不参与量纲运算，只有温差对应 TemperatureDim
+    TemperatureDifference: TemperatureDim => TemperatureDifference::from_kelvin;
+    HeatCapacity: HeatCapacityDim => HeatCapacity::from_joule_per_kelvin;
+    Velocity: VelocityDim => Velocity::from_m_per_sec;
+    Acceleration: AccelerationDim => Acceleration::from_m_per_s2;
+    AngularVelocity: AngularVelocityDim => AngularVelocity::from_rad_per_second;
+    AngularAcceleration: AngularAccelerationDim => AngularAcceleration::from_rad_per_second2;
+    Momentum: MomentumDim => Momentum::from_kg_m_s;
+    AngularMomentum: AngularMomentumDim => AngularMomentum::from_nms;
+    Force: ForceDim => Force::from_newton;
+    Torque: TorqueDim => Torque::from_nm;
+    Energy: EnergyDim => Energy::from_joule;
+    Power: PowerDim => Power::from_watt;
+    MagneticInduction: MagneticInductionDim => MagneticInduction::from_tesla;
+    MagneticMoment: MagneticMomentDim => MagneticMoment::from_am2;
+    MagneticAngularVelocity: MagneticAngularVelocityDim => MagneticAngularVelocity::from_tesla_rad_per_second;
+}
+
+// 没有手写运算关系的具名物理量之间直接相乘相除，结果为 Quantity，量纲由类型系统推导。
+// 已有的手写运算（Force * Distance = Energy 等）仍返回具名物理量，这里不重复生成
+macro_rules! impl_dimensional_ops {
+    ($op:ident, $method:ident, $output:ident: $($lhs:ident => [$($rhs:ident),+];)+) => {
+        $($(
+            impl $op<$rhs> for $lhs {
+                type Output = Quantity<
+                    $output<<$lhs as Dimensional>::Dimension, <$rhs as Dimensional>::Dimension>,
+                >;
+                fn $method(self, rhs: $rhs) -> Self::Output {
+                    $op::$method(self.quantity(), rhs)
+                }
+            }
+        )+)+
+    };
+}
+
+impl_dimensional_ops! {
+    Mul, mul, Prod:
+    Coef => [Angular, Distance, Mass, Area, Volume, TemperatureDifference, HeatCapacity, Velocity,
+        Acceleration, AngularVelocity, AngularAcceleration, Momentum, AngularMomentum, Force,
+        Torque, Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Angular => [Angular, Distance, Mass, Area, Volume, Time, TemperatureDifference, HeatCapacity,
+        Velocity, Acceleration, AngularVelocity, AngularAcceleration, Momentum, AngularMomentum,
+        Force, Torque, Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Distance => [Angular, Mass, Volume, Time, TemperatureDifference, HeatCapacity, Velocity,
+        Acceleration, AngularVelocity, AngularAcceleration, AngularMomentum, Torque, Energy, Power,
+        MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Mass => [Angular, Distance, Mass, Area, Volume, Time, TemperatureDifference, HeatCapacity,
+        AngularVelocity, AngularAcceleration, Momentum, AngularMomentum, Force, Torque, Energy,
+        Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Area => [Angular, Mass, Area, Volume, Time, TemperatureDifference, HeatCapacity, Velocity,
+        Acceleration, AngularVelocity, AngularAcceleration, Momentum, AngularMomentum, Force,
+        Torque, Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Volume => [Angular, Distance, Mass, Area, Volume, Time, TemperatureDifference, HeatCapacity,
+        Velocity, Acceleration, AngularVelocity, AngularAcceleration, Momentum, AngularMomentum,
+        Force, Torque, Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Time => [Angular, Distance, Mass, Area, Volume, Time, TemperatureDifference, HeatCapacity,
+        Velocity, Acceleration, AngularVelocity, AngularAcceleration, Momentum, AngularMomentum,
+        Force, Torque, Energy, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    TemperatureDifference => [Angular, Distance, Mass, Area, Volume, Time, TemperatureDifference,
+        Velocity, Acceleration, AngularVelocity, AngularAcceleration, Momentum, AngularMomentum,
+        Force, Torque, Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    HeatCapacity => [Angular, Distance, Mass, Area, Volume, Time, HeatCapacity, Velocity,
+        Acceleration, AngularVelocity, AngularAcceleration, Momentum, AngularMomentum, Force,
+        Torque, Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Velocity => [Angular, Distance, Area, Volume, TemperatureDifference, HeatCapacity, Velocity,
+        Acceleration, AngularVelocity, AngularAcceleration, Momentum, AngularMomentum, Torque,
+        Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Acceleration => [Angular, Distance, Area, Volume, TemperatureDifference, HeatCapacity, Velocity,
+        Acceleration, AngularVelocity, AngularAcceleration, Momentum, AngularMomentum, Force,
+        Torque, Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    AngularVelocity => [Angular, Mass, Area, Volume, TemperatureDifference, HeatCapacity, Velocity,
+        Acceleration, AngularVelocity, AngularAcceleration, Momentum, Force, Torque, Energy, Power,
+        MagneticMoment, MagneticAngularVelocity];
+    AngularAcceleration => [Angular, Mass, Area, Volume, TemperatureDifference, HeatCapacity,
+        Velocity, Acceleration, AngularVelocity, AngularAcceleration, Momentum, AngularMomentum,
+        Force, Torque, Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Momentum => [Angular, Mass, Area, Volume, Time, TemperatureDifference, HeatCapacity, Velocity,
+        Acceleration, AngularVelocity, AngularAcceleration, Momentum, AngularMomentum, Force,
+        Torque, Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    AngularMomentum => [Angular, Distance, Mass, Area, Volume, Time, TemperatureDifference,
+        HeatCapacity, Velocity, Acceleration, AngularAcceleration, Momentum, AngularMomentum, Force,
+        Torque, Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Force => [Angular, Mass, Area, Volume, Time, TemperatureDifference, HeatCapacity, Acceleration,
+        AngularVelocity, AngularAcceleration, Momentum, AngularMomentum, Force, Torque, Energy,
+        Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Torque => [Angular, Mass, Area, Volume, Time, TemperatureDifference, HeatCapacity, Velocity,
+        Acceleration, AngularAcceleration, Momentum, AngularMomentum, Force, Torque, Energy, Power,
+        MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Energy => [Angular, Distance, Mass, Area, Volume, Time, TemperatureDifference, HeatCapacity,
+        Velocity, Acceleration, AngularVelocity, AngularAcceleration, Momentum, AngularMomentum,
+        Force, Torque, Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Power => [Angular, Distance, Mass, Area, Volume, TemperatureDifference, HeatCapacity, Velocity,
+        Acceleration, AngularVelocity, AngularAcceleration, Momentum, AngularMomentum, Force,
+        Torque, Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    MagneticInduction => [Coef, Angular, Distance, Mass, Area, Volume, Time, TemperatureDifference,
+        HeatCapacity, Velocity, Acceleration, AngularAcceleration, Momentum, AngularMomentum, Force,
+        Torque, Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    MagneticMoment => [Angular, Distance, Mass, Area, Volume, Time, TemperatureDifference,
+        HeatCapacity, Velocity, Acceleration, AngularVelocity, AngularAcceleration, Momentum,
+        AngularMomentum, Force, Torque, Energy, Power, MagneticMoment, MagneticAngularVelocity];
+    MagneticAngularVelocity => [Angular, Distance, Mass, Area, Volume, Time, TemperatureDifference,
+        HeatCapacity, Velocity, Acceleration, AngularVelocity, AngularAcceleration, Momentum,
+        AngularMomentum, Force, Torque, Energy, Power, MagneticInduction, MagneticMoment,
+        MagneticAngularVelocity];
+}
+
+impl_dimensional_ops! {
+    Div, div, Quot:
+    Coef => [Angular, Distance, Mass, Area, Volume, Time, TemperatureDifference, HeatCapacity,
+        Velocity, Acceleration, AngularVelocity, AngularAcceleration, Momentum, AngularMomentum,
+        Force, Torque, Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Angular => [Angular, Distance, Mass, Area, Volume, TemperatureDifference, HeatCapacity,
+        Velocity, Acceleration, AngularAcceleration, Momentum, AngularMomentum, Force, Torque,
+        Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Distance => [Angular, Mass, Area, Volume, TemperatureDifference, HeatCapacity, Acceleration,
+        AngularVelocity, AngularAcceleration, Momentum, AngularMomentum, Force, Torque, Energy,
+        Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Mass => [Angular, Distance, Mass, Area, Volume, Time, TemperatureDifference, HeatCapacity,
+        Velocity, Acceleration, AngularVelocity, AngularAcceleration, Momentum, AngularMomentum,
+        Force, Torque, Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Area => [Angular, Mass, Volume, Time, TemperatureDifference, HeatCapacity, Velocity,
+        Acceleration, AngularVelocity, AngularAcceleration, Momentum, AngularMomentum, Force,
+        Torque, Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Volume => [Angular, Mass, Area, Time, TemperatureDifference, HeatCapacity, Velocity,
+        Acceleration, AngularVelocity, AngularAcceleration, Momentum, AngularMomentum, Force,
+        Torque, Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Time => [Angular, Distance, Mass, Area, Volume, TemperatureDifference, HeatCapacity, Velocity,
+        Acceleration, AngularVelocity, AngularAcceleration, Momentum, AngularMomentum, Force,
+        Torque, Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    TemperatureDifference => [Angular, Distance, Mass, Area, Volume, Time, HeatCapacity, Velocity,
+        Acceleration, AngularVelocity, AngularAcceleration, Momentum, AngularMomentum, Force,
+        Torque, Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    HeatCapacity => [Angular, Distance, Mass, Area, Volume, Time, TemperatureDifference,
+        HeatCapacity, Velocity, Acceleration, AngularVelocity, AngularAcceleration, Momentum,
+        AngularMomentum, Force, Torque, Energy, Power, MagneticInduction, MagneticMoment,
+        MagneticAngularVelocity];
+    Velocity => [Angular, Mass, Area, Volume, TemperatureDifference, HeatCapacity, Velocity,
+        AngularVelocity, AngularAcceleration, Momentum, AngularMomentum, Force, Torque, Energy,
+        Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Acceleration => [Angular, Distance, Mass, Area, Volume, Time, TemperatureDifference,
+        HeatCapacity, Velocity, Acceleration, AngularVelocity, AngularAcceleration, Momentum,
+        AngularMomentum, Force, Torque, Energy, Power, MagneticInduction, MagneticMoment,
+        MagneticAngularVelocity];
+    AngularVelocity => [Angular, Distance, Mass, Area, Volume, TemperatureDifference, HeatCapacity,
+        Velocity, Acceleration, Momentum, AngularMomentum, Force, Torque, Energy, Power,
+        MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    AngularAcceleration => [Angular, Distance, Mass, Area, Volume, Time, TemperatureDifference,
+        HeatCapacity, Velocity, Acceleration, AngularVelocity, AngularAcceleration, Momentum,
+        AngularMomentum, Force, Torque, Energy, Power, MagneticInduction, MagneticMoment,
+        MagneticAngularVelocity];
+    Momentum => [Angular, Distance, Mass, Area, Volume, Time, TemperatureDifference, HeatCapacity,
+        Velocity, Acceleration, AngularVelocity, AngularAcceleration, Momentum, AngularMomentum,
+        Force, Torque, Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    AngularMomentum => [Angular, Distance, Mass, Area, Volume, Time, TemperatureDifference,
+        HeatCapacity, Velocity, Acceleration, AngularAcceleration, Momentum, Force, Torque, Energy,
+        Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Force => [Angular, Distance, Mass, Area, Volume, Time, TemperatureDifference, HeatCapacity,
+        Velocity, Acceleration, AngularVelocity, AngularAcceleration, Momentum, AngularMomentum,
+        Force, Torque, Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Torque => [Angular, Mass, Area, Volume, Time, TemperatureDifference, HeatCapacity, Velocity,
+        Acceleration, AngularAcceleration, Momentum, AngularMomentum, Force, Torque, Energy, Power,
+        MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Energy => [Angular, Mass, Area, Volume, Velocity, Acceleration, AngularVelocity,
+        AngularAcceleration, Momentum, AngularMomentum, Force, Torque, Energy, Power,
+        MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    Power => [Angular, Distance, Mass, Area, Volume, Time, TemperatureDifference, HeatCapacity,
+        Acceleration, AngularVelocity, AngularAcceleration, Momentum, AngularMomentum, Torque,
+        Energy, Power, MagneticInduction, MagneticMoment, MagneticAngularVelocity];
+    MagneticInduction => [Coef, Angular, Distance, Mass, Area, Volume, Time, TemperatureDifference,
+        HeatCapacity, Velocity, Acceleration, AngularVelocity, AngularAcceleration, Momentum,
+        AngularMomentum, Force, Torque, Energy, Power, MagneticInduction, MagneticMoment,
+        MagneticAngularVelocity];
+    MagneticMoment => [Angular, Distance, Mass, Area, Volume, Time, TemperatureDifference,
+        HeatCapacity, Velocity, Acceleration, AngularVelocity, AngularAcceleration, Momentum,
+        AngularMomentum, Force, Torque, Energy, Power, MagneticInduction, MagneticMoment,
+        MagneticAngularVelocity];
+    MagneticAngularVelocity => [Angular, Distance, Mass, Area, Volume, Time, TemperatureDifference,
+        HeatCapacity, Velocity, Acceleration, AngularVelocity, AngularAcceleration, Momentum,
+        AngularMomentum, Force, Torque, Energy, Power, MagneticInduction, MagneticMoment,
+        MagneticAngularVelocity];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_exponents() {
+        assert_eq!(EnergyDim::EXPONENTS, [2, 1, -2, 0, 0, 0, 0]);
+        let q = Force::from_newton(1.0).quantity() / Area::from_m2(1.0);
+        assert_eq!(q.exponents(), [-1, 1, -2, 0, 0, 0, 0]);
+        assert_eq!(Coef::new(2.0).quantity().exponents(), [0; 7]);
+    }
+
+    #[test]
+    fn test_products_without_manual_impls() {
+        let energy: Energy =
+            (Torque::from_mill_nm(500.0).quantity() * Angular::from_deg(180.0)).into();
+        assert_relative_eq!(
+            energy.as_joule(),
+            0.5 * std::f64::consts::PI,
+            epsilon = 1e-12
+        );
+
+        let inertia: MomentOfInertia = Mass::from_g(500.0).quantity() * Area::from_m2(4.0);
+        assert_relative_eq!(inertia.value(), 2.0);
+        let momentum: AngularMomentum =
+            (inertia * AngularVelocity::from_rad_per_second(3.0)).into();
+        assert_relative_eq!(momentum.as_nms(), 6.0);
+
+        // τ = m × B
+        let torque: Torque = (MagneticMoment::from_am2(2.0).quantity()
+            * MagneticInduction::from_micro_tesla(30.0))
+        .into();
+        assert_relative_eq!(torque.as_micro_nm(), 60.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_quotients() {
+        let force: Force =
+            (Power::from_kilo_watt(3.0).quantity() / Velocity::from_m_per_sec(30.0)).into();
+        assert_relative_eq!(force.as_newton(), 100.0);
+        let distance: Distance =
+            (Energy::from_joule(10.0).quantity() / Force::from_newton(4.0)).into();
+        assert_relative_eq!(distance.as_m(), 2.5);
+        let ratio: Coef = (Distance::from_km(1.0).quantity() / Distance::from_m(250.0)).into();
+        assert_relative_eq!(ratio.get_value(), 4.0);
+    }
+
+    #[test]
+    fn test_quantity_arithmetic() {
+        let a = Quantity::<LengthDim>::from(Distance::from_km(1.0));
+        let b = Distance::from_m(200.0).quantity();
+        assert_eq!(a + b, Quantity::new(1200.0));
+        assert_eq!(a - b, Quantity::new(800.0));
+        assert_eq!(-b * 2.0, Quantity::new(-400.0));
+        assert_eq!(2.0 * b / 4.0, Quantity::new(100.0));
+        assert!(b < a);
+        let q: &dyn PhysicalQuantity = &a;
+        assert_eq!(q.default_unit_value(), 1000.0);
+        assert!(Quantity::<LengthDim>::default().is_zero());
+        assert_eq!(Distance::from(a).as_m(), 1000.0);
+    }
+
+    #[test]
+    fn test_named_products_and_quotients() {
+        let work: Energy = (Torque::from_nm(2.0) * Angular::from_rad(3.0)).into();
+        assert_eq!(work.as_joule(), 6.0);
+        let inertia: MomentOfInertia = Mass::from_kg(2.0) * Area::from_m2(0.5);
+        assert_eq!(inertia.value(), 1.0);
+        let momentum: Momentum = (Mass::from_g(500.0) * Velocity::from_km_per_h(36.0)).into();
+        assert_relative_eq!(momentum.as_kg_m_s(), 5.0, epsilon = 1e-12);
+        let impulse: Momentum = (Force::from_newton(4.0) * Time::from_minute(1.0)).into();
+        assert_relative_eq!(impulse.as_kg_m_s(), 240.0);
+
+        let acceleration: Acceleration =
+            (Force::from_kilo_newton(2.0) / Mass::from_kg(500.0)).into();
+        assert_relative_eq!(acceleration.as_m_per_s2(), 4.0);
+        let jerk = AngularAcceleration::from_rad_per_second2(6.0) / Time::from_second(2.0);
+        assert_eq!(jerk.exponents(), [0, 0, -3, 0, 0, 0, 0]);
+        assert_relative_eq!(jerk.value(), 3.0);
+        let pressure = Force::from_newton(6.0) / Area::from_m2(2.0);
+        assert_eq!(pressure.exponents(), [-1, 1, -2, 0, 0, 0, 0]);
+        assert_relative_eq!(pressure.value(), 3.0);
+
+        // 手写的运算关系仍返回具名物理量
+        let energy: Energy = Force::from_newton(2.0) * Distance::from_m(3.0);
+        assert_eq!(energy.as_joule(), 6.0);
+    }
+}
@@ -0,0 +1,481 @@
+use super::parse::prefix_power;
+use super::unit::{UnitQuantity, UnitSymbol};
+use super::{Coef, Dimension, Quantity, Vector3, VectorQuantity};
+use std::fmt::{Display, Formatter};
+
+// 自动词头只使用指数为 3 的倍数的工程词头
+const ENGINEERING_PREFIXES: [(&str, i32); 13] = [
+    ("E", 18),
+    ("P", 15),
+    ("T", 12),
+    ("G", 9),
+    ("M", 6),
+    ("k", 3),
+    ("", 0),
+    ("m", -3),
+    ("μ", -6),
+    ("n", -9),
+    ("p", -12),
+    ("f", -15),
+    ("a", -18),
+];
+
+// 国际单位制基本单位，顺序与 Dimension::EXPONENTS 一致
+const SI_BASE_SYMBOLS: [&str; 7] = ["m", "kg", "s", "A", "K", "mol", "cd"];
+
+// 以 10^exp 为单位表示 v，用整数幂相乘或相除，避免 1e-3 这类常数的舍入误差
+fn scale(v: f64, exp: i32) -> f64 {
+    if exp >= 0 {
+        v / 10f64.powi(exp)
+    } else {
+        v * 10f64.powi(-exp)
+    }
+}
+
+fn write_value(f: &mut Formatter<'_>, v: f64, precision: Option<usize>) -> std::fmt::Result {
+    match precision {
+        Some(precision) => write!(f, "{:.*}", precision, v),
+        None => write!(f, "{}", v),
+    }
+}
+
+// 度的符号紧跟数值，其余单位与数值之间留一个空格
+fn write_symbol(f: &mut Formatter<'_>, symbol: &str) -> std::fmt::Result {
+    if symbol == "°" {
+        write!(f, "{}", symbol)
+    } else {
+        write!(f, " {}", symbol)
+    }
+}
+
+fn superscript(exponent: i32) -> String {
+    exponent
+        .to_string()
+        .chars()
+        .map(|c| match c {
+            '-' => '⁻',
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            _ => '⁹',
+        })
+        .collect()
+}
+
+fn unit_factor<Q: UnitQuantity>(unit: Q::Unit) -> f64 {
+    Q::with_unit(unit, 1.0).default_unit_value()
+}
+
+// 国际单位制数值 si 在 unit 下的数值，兼容带零点偏移的单位（如 °C、°F）
+fn unit_value<Q: UnitQuantity>(unit: Q::Unit, si: f64) -> f64 {
+    let offset = Q::with_unit(unit, 0.0).default_unit_value();
+    (si - offset) / (unit_factor::<Q>(unit) - offset)
+}
+
+// 输出单位的选择方式
+#[derive(Clone, Copy)]
+enum UnitChoice<U> {
+    // 构造时使用的单位
+    Stored,
+    Unit(U),
+    AutoPrefix,
+}
+
+// 实际输出的单位：枚举中的单位，或基准单位加词头（如 mm、GW）
+#[derive(Clone, Copy)]
+enum Target<U> {
+    Unit(U),
+    Prefixed {
+        base: U,
+        prefix: &'static str,
+        exp: i32,
+        // 与枚举中同名的单位，例如 mN·m
+        unit: Option<U>,
+    },
+}
+
+impl<U: UnitSymbol> Target<U> {
+    // reference 决定默认单位，magnitude 为国际单位制下用来挑选词头的数值
+    fn resolve<Q>(choice: UnitChoice<U>, reference: U, magnitude: f64) -> Self
+    where
+        Q: UnitQuantity<Unit = U>,
+    {
+        let base = match (choice, Q::PREFIX_BASE) {
+            (UnitChoice::Unit(unit), _) => return Target::Unit(unit),
+            (UnitChoice::AutoPrefix, Some(base)) => base,
+            _ => return Target::Unit(reference),
+        };
+        let power = prefix_power(base.symbol());
+        let v = (magnitude / unit_factor::<Q>(base)).abs();
+        let (prefix, exp) = if v == 0.0 || !v.is_finite() {
+            ("", 0)
+        } else {
+            ENGINEERING_PREFIXES
+                .iter()
+                .copied()
+                .find(|&(_, exp)| v >= 10f64.powi(exp * power))
+                .unwrap_or(ENGINEERING_PREFIXES[ENGINEERING_PREFIXES.len() - 1])
+        };
+        let symbol = format!("{}{}", prefix, base.symbol());
+        Target::Prefixed {
+            base,
+            prefix,
+            exp: exp * power,
+            unit: U::units()
+                .iter()
+                .copied()
+                .find(|unit| unit.symbols().contains(&symbol.as_str())),
+        }
+    }
+
+    fn convert<Q>(&self, quantity: &Q) -> f64
+    where
+        Q: UnitQuantity<Unit = U>,
+    {
+        match *self {
+            Target::Unit(unit) if unit == quantity.unit() => quantity.value(),
+            Target::Unit(unit) => unit_value::<Q>(unit, quantity.default_unit_value()),
+            Target::Prefixed {
+                unit: Some(unit), ..
+            } if unit == quantity.unit() => quantity.value(),
+            Target::Prefixed { base, exp, .. } => scale(Target::Unit(base).convert(quantity), exp),
+        }
+    }
+
+    fn symbol(&self) -> String {
+        match self {
+            Target::Unit(unit) => unit.symbol().to_string(),
+            Target::Prefixed { base, prefix, .. } => format!("{}{}", prefix, base.symbol()),
+        }
+    }
+}
+
+/// 物理量的输出选项，例如 `12.3 mN·m`
+pub struct QuantityFormat<'a, Q>
+where
+    Q: UnitQuantity,
+{
+    quantity: &'a Q,
+    choice: UnitChoice<Q::Unit>,
+    precision: Option<usize>,
+}
+
+impl<'a, Q> QuantityFormat<'a, Q>
+where
+    Q: UnitQuantity,
+{
+    pub(crate) fn new(quantity: &'a Q) -> Self {
+        QuantityFormat {
+            quantity,
+            choice: UnitChoice::Stored,
+            precision: None,
+        }
+    }
+
+    // 换算到指定单位输出
+    pub fn unit(mut self, unit: Q::Unit) -> Self {
+        self.choice = UnitChoice::Unit(unit);
+        self
+    }
+
+    // 按数值大小选择工程词头，使数值落在 [1, 1000)，例如 0.0123 N·m 输出为 12.3 mN·m
+    pub fn auto_prefix(mut self) -> Self {
+        self.choice = UnitChoice::AutoPrefix;
+        self
+    }
+
+    // 保留的小数位数
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+}
+
+impl<'a, Q> Display for QuantityFormat<'a, Q>
+where
+    Q: UnitQuantity,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let target = Target::resolve::<Q>(
+            self.choice,
+            self.quantity.unit(),
+            self.quantity.default_unit_value(),
+        );
+        // Formatter 的精度优先，例如 {:.2}
+        write_value(
+            f,
+            target.convert(self.quantity),
+            f.precision().or(self.precision),
+        )?;
+        write_symbol(f, &target.symbol())
+    }
+}
+
+/// 向量的输出选项，三个分量使用同一单位，例如 `[1, 2, 3] nT`
+pub struct VectorFormat<'a, T>
+where
+    T: UnitQuantity + VectorQuantity + Default,
+{
+    vector: &'a Vector3<T>,
+    choice: UnitChoice<T::Unit>,
+    precision: Option<usize>,
+}
+
+impl<'a, T> VectorFormat<'a, T>
+where
+    T: UnitQuantity + VectorQuantity + Default,
+{
+    // 换算到指定单位输出
+    pub fn unit(mut self, unit: T::Unit) -> Self {
+        self.choice = UnitChoice::Unit(unit);
+        self
+    }
+
+    // 按绝对值最大的分量选择工程词头
+    pub fn auto_prefix(mut self) -> Self {
+        self.choice = UnitChoice::AutoPrefix;
+        self
+    }
+
+    // 保留的小数位数
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+}
+
+impl<'a, T> Display for VectorFormat<'a, T>
+where
+    T: UnitQuantity + VectorQuantity + Default,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let components = [&self.vector.x, &self.vector.y, &self.vector.z];
+        // 分量单位不同时统一换算到 x 的单位
+        let magnitude = components
+            .iter()
+            .map(|c| c.default_unit_value().abs())
+            .fold(0.0, f64::max);
+        let target = Target::resolve::<T>(self.choice, self.vector.x.unit(), magnitude);
+        let precision = f.precision().or(self.precision);
+        write!(f, "[")?;
+        for (i, component) in components.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write_value(f, target.convert(*component), precision)?;
+        }
+        write!(f, "]")?;
+        write_symbol(f, &target.symbol())
+    }
+}
+
+impl<T> Vector3<T>
+where
+    T: UnitQuantity + VectorQuantity + Default,
+{
+    // 可指定输出单位、自动词头与精度
+    pub fn formatted(&self) -> VectorFormat<'_, T> {
+        VectorFormat {
+            vector: self,
+            choice: UnitChoice::Stored,
+            precision: None,
+        }
+    }
+}
+
+impl<T> Display for Vector3<T>
+where
+    T: UnitQuantity + VectorQuantity + Default,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.formatted(), f)
+    }
+}
+
+impl Display for Coef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_value(f, self.get_value(), f.precision())
+    }
+}
+
+impl Display for Vector3<Coef> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+        write_value(f, self.x.get_value(), f.precision())?;
+        write!(f, ", ")?;
+        write_value(f, self.y.get_value(), f.precision())?;
+        write!(f, ", ")?;
+        write_value(f, self.z.get_value(), f.precision())?;
+        write!(f, "]")
+    }
+}
+
+// 以国际单位制基本单位输出，例如 6 kg·m²·s⁻²
+impl<D: Dimension> Display for Quantity<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_value(f, self.value(), f.precision())?;
+        let unit: Vec<String> = [1, 0, 2, 3, 4, 5, 6]
+            .iter()
+            .filter(|&&i| D::EXPONENTS[i] != 0)
+            .map(|&i| match D::EXPONENTS[i] {
+                1 => SI_BASE_SYMBOLS[i].to_string(),
+                exponent => format!("{}{}", SI_BASE_SYMBOLS[i], superscript(exponent)),
+            })
+            .collect();
+        if unit.is_empty() {
+            Ok(())
+        } else {
+            write!(f, " {}", unit.join("·"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::basic::*;
+
+    #[test]
+    fn test_display_stored_unit() {
+        assert_eq!(Torque::from_mill_nm(12.3).to_string(), "12.3 mN·m");
+        assert_eq!(
+            MagneticInduction::from_nano_tesla(-40.0).to_string(),
+            "-40 nT"
+        );
+        assert_eq!(Velocity::from_km_per_h(12.5).to_string(), "12.5 km/h");
+        assert_eq!(Angular::from_deg(90.0).to_string(), "90°");
+        assert_eq!(
+            format!("{:.2}", Energy::from_kilo_joule(1.0 / 3.0)),
+            "0.33 kJ"
+        );
+        assert_eq!(Coef::new(0.5).to_string(), "0.5");
+        let t: Torque = Torque::from_micro_nm(7.5).to_string().parse().unwrap();
+        assert_eq!(t, Torque::from_micro_nm(7.5));
+    }
+
+    #[test]
+    fn test_display_target_unit() {
+        let t = Torque::from_nm(0.5);
+        assert_eq!(
+            t.formatted()
+                .unit(TorqueType::MillNM)
+                .precision(1)
+                .to_string(),
+            "500.0 mN·m"
+        );
+        let d = Distance::from_km(1.5);
+        assert_eq!(d.formatted().unit(DistanceType::M).to_string(), "1500 m");
+        assert_eq!(
+            format!(
+                "{:.3}",
+                Angular::from_rad(1.0).formatted().unit(AngularType::Deg)
+            ),
+            "57.296°"
+        );
+    }
+
+    #[test]
+    fn test_display_auto_prefix() {
+        assert_eq!(
+            Torque::from_nm(0.0125)
+                .formatted()
+                .auto_prefix()
+                .to_string(),
+            "12.5 mN·m"
+        );
+        assert_eq!(
+            Power::from_watt(2.5e9)
+                .formatted()
+                .auto_prefix()
+                .to_string(),
+            "2.5 GW"
+        );
+        assert_eq!(
+            Distance::from_m(0.005)
+                .formatted()
+                .auto_prefix()
+                .to_string(),
+            "5 mm"
+        );
+        assert_eq!(
+            Mass::from_g(2500.0).formatted().auto_prefix().to_string(),
+            "2.5 kg"
+        );
+        assert_eq!(
+            Mass::from_kg(1.5).formatted().auto_prefix().to_string(),
+            "1.5 kg"
+        );
+        assert_eq!(
+            Area::from_m2(2e-6).formatted().auto_prefix().to_string(),
+            "2 mm²"
+        );
+        assert_eq!(
+            Force::from_newton(0.0)
+                .formatted()
+                .auto_prefix()
+                .to_string(),
+            "0 N"
+        );
+        // 没有基准单位时保留原单位
+        assert_eq!(
+            Momentum::from_kg_m_s(3.0)
+                .formatted()
+                .auto_prefix()
+                .to_string(),
+            "3 kg·m/s"
+        );
+    }
+
+    #[test]
+    fn test_display_vector() {
+        let b = Vector3::new(
+            MagneticInduction::from_nano_tesla(1.0),
+            MagneticInduction::from_nano_tesla(2.0),
+            MagneticInduction::from_nano_tesla(3.0),
+        );
+        assert_eq!(b.to_string(), "[1, 2, 3] nT");
+        assert_eq!(format!("{:.1}", b), "[1.0, 2.0, 3.0] nT");
+        let mixed = Vector3::new(
+            MagneticInduction::from_micro_tesla(2.0),
+            MagneticInduction::from_nano_tesla(500.0),
+            MagneticInduction::from_micro_tesla(-1.0),
+        );
+        assert_eq!(
+            mixed.formatted().precision(1).to_string(),
+            "[2.0, 0.5, -1.0] μT"
+        );
+        assert_eq!(
+            mixed
+                .formatted()
+                .unit(MagneticInductionType::NanoTesla)
+                .precision(0)
+                .to_string(),
+            "[2000, 500, -1000] nT"
+        );
+        let d = Vector3::new(
+            Distance::from_m(1200.0),
+            Distance::from_m(-300.0),
+            Distance::from_m(0.0),
+        );
+        assert_eq!(d.formatted().auto_prefix().to_string(), "[1.2, -0.3, 0] km");
+        assert_eq!(
+            Vector3::new(Coef::new(1.0), Coef::new(0.5), Coef::new(0.0)).to_string(),
+            "[1, 0.5, 0]"
+        );
+    }
+
+    #[test]
+    fn test_display_dimension_quantity() {
+        let work = Force::from_newton(2.0).quantity() * Distance::from_m(3.0);
+        assert_eq!(work.to_string(), "6 kg·m²·s⁻²");
+        let pressure = Force::from_newton(1.0).quantity() / Area::from_m2(1.0);
+        assert_eq!(pressure.to_string(), "1 kg·m⁻¹·s⁻²");
+        assert_eq!(Coef::new(2.0).quantity().to_string(), "2");
+    }
+}
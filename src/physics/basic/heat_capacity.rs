@@ -0,0 +1,232 @@
+use crate::physics::basic::{
+    Coef, Energy, HeatCapacity, HeatCapacityType, PhysicalQuantity, TemperatureDifference,
+};
+use std::any::Any;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+impl Default for HeatCapacity {
+    fn default() -> Self {
+        Self::from_joule_per_kelvin(0.0)
+    }
+}
+
+impl PhysicalQuantity for HeatCapacity {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_zero(&self) -> bool {
+        self.v == 0.0
+    }
+
+    fn default_unit_value(&self) -> f64 {
+        self.as_joule_per_kelvin()
+    }
+
+    fn set_value(&mut self, value: f64) {
+        self.v = value;
+    }
+}
+
+impl HeatCapacity {
+    pub fn from_joule_per_kelvin(joule_per_kelvin: f64) -> Self {
+        Self {
+            default_type: HeatCapacityType::JoulePerKelvin,
+            v: joule_per_kelvin,
+        }
+    }
+
+    pub fn from_mill_joule_per_kelvin(mill_joule_per_kelvin: f64) -> Self {
+        Self {
+            default_type: HeatCapacityType::MillJoulePerKelvin,
+            v: mill_joule_per_kelvin,
+        }
+    }
+
+    pub fn from_kilo_joule_per_kelvin(kilo_joule_per_kelvin: f64) -> Self {
+        Self {
+            default_type: HeatCapacityType::KiloJoulePerKelvin,
+            v: kilo_joule_per_kelvin,
+        }
+    }
+
+    pub fn as_joule_per_kelvin(&self) -> f64 {
+        match self.default_type {
+            HeatCapacityType::JoulePerKelvin => self.v,
+            HeatCapacityType::MillJoulePerKelvin => self.v * 1e-3,
+            HeatCapacityType::KiloJoulePerKelvin => self.v * 1e3,
+        }
+    }
+
+    pub fn as_mill_joule_per_kelvin(&self) -> f64 {
+        match self.default_type {
+            HeatCapacityType::MillJoulePerKelvin => self.v,
+            _ => self.as_joule_per_kelvin() * 1e3,
+        }
+    }
+
+    pub fn as_kilo_joule_per_kelvin(&self) -> f64 {
+        match self.default_type {
+            HeatCapacityType::KiloJoulePerKelvin => self.v,
+            _ => self.as_joule_per_kelvin() * 1e-3,
+        }
+    }
+}
+
+impl Add for HeatCapacity {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        let v = self.as_joule_per_kelvin() + rhs.as_joule_per_kelvin();
+        Self::from_joule_per_kelvin(v)
+    }
+}
+
+impl Sub for HeatCapacity {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        let v = self.as_joule_per_kelvin() - rhs.as_joule_per_kelvin();
+        Self::from_joule_per_kelvin(v)
+    }
+}
+
+impl Mul<f64> for HeatCapacity {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self::Output {
+        let v = self.as_joule_per_kelvin() * rhs;
+        Self::from_joule_per_kelvin(v)
+    }
+}
+
+impl Div<f64> for HeatCapacity {
+    type Output = Self;
+    fn div(self, rhs: f64) -> Self::Output {
+        let v = self.as_joule_per_kelvin() / rhs;
+        Self::from_joule_per_kelvin(v)
+    }
+}
+
+impl Mul<HeatCapacity> for f64 {
+    type Output = HeatCapacity;
+    fn mul(self, rhs: HeatCapacity) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<Coef> for HeatCapacity {
+    type Output = Self;
+    fn mul(self, rhs: Coef) -> Self::Output {
+        self * rhs.get_value()
+    }
+}
+
+impl Div<Coef> for HeatCapacity {
+    type Output = Self;
+    fn div(self, rhs: Coef) -> Self::Output {
+        self / rhs.get_value()
+    }
+}
+
+impl Neg for HeatCapacity {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::from_joule_per_kelvin(-self.as_joule_per_kelvin())
+    }
+}
+
+// 热容 × 温差 = 能量（吸收或放出的热量）
+impl Mul<TemperatureDifference> for HeatCapacity {
+    type Output = Energy;
+    fn mul(self, rhs: TemperatureDifference) -> Self::Output {
+        Energy::from_joule(self.as_joule_per_kelvin() * rhs.as_kelvin())
+    }
+}
+
+// 温差 × 热容 = 能量，满足交换律
+impl Mul<HeatCapacity> for TemperatureDifference {
+    type Output = Energy;
+    fn mul(self, rhs: HeatCapacity) -> Self::Output {
+        rhs * self
+    }
+}
+
+// 能量 ÷ 温差 = 热容
+impl Div<TemperatureDifference> for Energy {
+    type Output = HeatCapacity;
+    fn div(self, rhs: TemperatureDifference) -> Self::Output {
+        HeatCapacity::from_joule_per_kelvin(self.as_joule() / rhs.as_kelvin())
+    }
+}
+
+// 能量 ÷ 热容 = 温差
+impl Div<HeatCapacity> for Energy {
+    type Output = TemperatureDifference;
+    fn div(self, rhs: HeatCapacity) -> Self::Output {
+        TemperatureDifference::from_kelvin(self.as_joule() / rhs.as_joule_per_kelvin())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::basic::{Power, Temperature, Time};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_heat_capacity_units() {
+        let c = HeatCapacity::from_kilo_joule_per_kelvin(1.5);
+        assert_relative_eq!(c.as_joule_per_kelvin(), 1500.0);
+        assert_relative_eq!(c.as_mill_joule_per_kelvin(), 1.5e6);
+        assert_relative_eq!(
+            HeatCapacity::from_mill_joule_per_kelvin(2.0).as_kilo_joule_per_kelvin(),
+            2e-6
+        );
+        assert!(HeatCapacity::default().is_zero());
+    }
+
+    #[test]
+    fn test_heat_capacity_arithmetic() {
+        let a = HeatCapacity::from_joule_per_kelvin(100.0);
+        let b = HeatCapacity::from_kilo_joule_per_kelvin(0.5);
+        assert_relative_eq!((a + b).as_joule_per_kelvin(), 600.0);
+        assert_relative_eq!((b - a).as_joule_per_kelvin(), 400.0);
+        assert_relative_eq!((a * 3.0).as_joule_per_kelvin(), 300.0);
+        assert_relative_eq!((3.0 * a).as_joule_per_kelvin(), 300.0);
+        assert_relative_eq!((a / 4.0).as_joule_per_kelvin(), 25.0);
+        assert_relative_eq!((a * Coef::new(0.5)).as_joule_per_kelvin(), 50.0);
+        assert_relative_eq!((a / Coef::new(0.5)).as_joule_per_kelvin(), 200.0);
+        assert_relative_eq!((-a).as_joule_per_kelvin(), -100.0);
+    }
+
+    #[test]
+    fn test_energy_relations() {
+        let c = HeatCapacity::from_joule_per_kelvin(900.0);
+        // 摄氏度温差与开尔文温差相同，华氏度温差按 5/9 换算
+        let q: Energy = c * TemperatureDifference::from_celsius(10.0);
+        assert_relative_eq!(q.as_joule(), 9000.0);
+        let q: Energy = TemperatureDifference::from_fahrenheit(18.0) * c;
+        assert_relative_eq!(q.as_joule(), 9000.0);
+        let dt = Energy::from_kilo_joule(4.5) / c;
+        assert_relative_eq!(dt.as_kelvin(), 5.0);
+        let c2 = Energy::from_joule(9000.0) / TemperatureDifference::from_kelvin(10.0);
+        assert_relative_eq!(c2.as_joule_per_kelvin(), 900.0);
+
+        // 加热器 60 s 输入 45 kJ，温度从 20 °C 升高到 70 °C
+        let heat = Power::from_watt(750.0) * Time::from_minute(1.0);
+        let end = Temperature::from_celsius(20.0) + heat / c;
+        assert_relative_eq!(end.as_celsius(), 70.0);
+    }
+
+    #[test]
+    fn test_heat_capacity_dimension() {
+        use crate::physics::basic::Dimensional;
+        let c: HeatCapacity =
+            (Energy::from_joule(100.0).quantity() / TemperatureDifference::from_kelvin(4.0)).into();
+        assert_relative_eq!(c.as_joule_per_kelvin(), 25.0);
+        assert_eq!(c.quantity().exponents(), [2, 1, -2, 0, -1, 0, 0]);
+        assert_eq!(c.to_string(), "25 J/K");
+        assert_eq!(
+            "2 kJ/K".parse::<HeatCapacity>().unwrap(),
+            HeatCapacity::from_kilo_joule_per_kelvin(2.0)
+        );
+    }
+}
@@ -0,0 +1,340 @@
+use super::unit::{UnitQuantity, UnitSymbol};
+use super::*;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// 解析带单位的物理量字符串时的错误
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseQuantityError {
+    Empty,
+    // 开头不是合法的数字
+    InvalidNumber(String),
+    MissingUnit {
+        quantity: &'static str,
+    },
+    UnknownUnit {
+        quantity: &'static str,
+        unit: String,
+    },
+    // 向量不是 [x, y, z] 形式，或分量个数不是 3
+    InvalidVector(String),
+}
+
+impl Display for ParseQuantityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseQuantityError::Empty => write!(f, "empty quantity string"),
+            ParseQuantityError::InvalidNumber(text) => write!(f, "invalid number in '{}'", text),
+            ParseQuantityError::MissingUnit { quantity } => {
+                write!(f, "missing unit for {}", quantity)
+            }
+            ParseQuantityError::UnknownUnit { quantity, unit } => {
+                write!(f, "unknown unit '{}' for {}", unit, quantity)
+            }
+            ParseQuantityError::InvalidVector(text) => write!(f, "invalid vector '{}'", text),
+        }
+    }
+}
+
+impl Error for ParseQuantityError {}
+
+// 国际单位制词头，按符号长度从长到短匹配
+const SI_PREFIXES: [(&str, f64); 21] = [
+    ("da", 1e1),
+    ("Y", 1e24),
+    ("Z", 1e21),
+    ("E", 1e18),
+    ("P", 1e15),
+    ("T", 1e12),
+    ("G", 1e9),
+    ("M", 1e6),
+    ("k", 1e3),
+    ("h", 1e2),
+    ("d", 1e-1),
+    ("c", 1e-2),
+    ("m", 1e-3),
+    ("μ", 1e-6),
+    ("u", 1e-6),
+    ("n", 1e-9),
+    ("p", 1e-12),
+    ("f", 1e-15),
+    ("a", 1e-18),
+    ("z", 1e-21),
+    ("y", 1e-24),
+];
+
+// 拆出开头最长的合法数字，剩余部分为单位
+fn split_number(s: &str) -> Result<(f64, &str), ParseQuantityError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(ParseQuantityError::Empty);
+    }
+    s.char_indices()
+        .map(|(i, c)| i + c.len_utf8())
+        .rev()
+        .find_map(|end| s[..end].parse::<f64>().ok().map(|v| (v, s[end..].trim())))
+        .ok_or_else(|| ParseQuantityError::InvalidNumber(s.to_string()))
+}
+
+// 统一单位写法：µ → μ，* . ⋅ 与空格 → ·，^2 与字母后的 2 → ²
+fn normalize_unit(unit: &str) -> String {
+    let mut out = String::with_capacity(unit.len());
+    let mut prev: Option<char> = None;
+    let mut chars = unit.chars().peekable();
+    while let Some(c) = chars.next() {
+        let mapped = match c {
+            'µ' => 'μ',
+            '*' | '.' | '⋅' | '•' | ' ' => '·',
+            '^' if matches!(chars.peek(), Some('2') | Some('3')) => continue,
+            '2' if prev.is_some_and(|p| p.is_ascii_alphabetic() || p == '^') => '²',
+            '3' if prev.is_some_and(|p| p.is_ascii_alphabetic() || p == '^') => '³',
+            c => c,
+        };
+        if !(mapped == '·' && out.ends_with('·')) {
+            out.push(mapped);
+        }
+        prev = Some(c);
+    }
+    out
+}
+
+fn find_unit<U: UnitSymbol>(symbol: &str) -> Option<U> {
+    U::units()
+        .iter()
+        .copied()
+        .find(|unit| unit.symbols().contains(&symbol))
+}
+
+// 词头作用于符号开头的单位，m² 的词头要取平方：1 mm² = 1e-6 m²
+pub(crate) fn prefix_power(symbol: &str) -> i32 {
+    match symbol.chars().find(|c| !c.is_alphabetic()) {
+        Some('²') => 2,
+        Some('³') => 3,
+        _ => 1,
+    }
+}
+
+pub(crate) fn parse_quantity<Q: UnitQuantity>(s: &str) -> Result<Q, ParseQuantityError> {
+    let (v, unit) = split_number(s)?;
+    if unit.is_empty() {
+        return Err(ParseQuantityError::MissingUnit { quantity: Q::NAME });
+    }
+    let unit = normalize_unit(unit);
+    if let Some(found) = find_unit::<Q::Unit>(&unit) {
+        return Ok(Q::with_unit(found, v));
+    }
+    // 枚举中没有的词头，例如 mm、GW、mrad，只能加在基准单位上，换算到基准单位。
+    // PREFIX_BASE 为 None 的物理量（绝对温度等带偏移的单位）不接受词头，mkm 这类叠加词头也不接受
+    if let Some(base) = Q::PREFIX_BASE {
+        for (prefix, factor) in SI_PREFIXES {
+            if unit
+                .strip_prefix(prefix)
+                .is_some_and(|rest| base.symbols().contains(&rest))
+            {
+                return Ok(Q::with_unit(
+                    base,
+                    v * factor.powi(prefix_power(base.symbol())),
+                ));
+            }
+        }
+    }
+    Err(ParseQuantityError::UnknownUnit {
+        quantity: Q::NAME,
+        unit,
+    })
+}
+
+// 系数无量纲，只接受数字
+impl FromStr for Coef {
+    type Err = ParseQuantityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (v, unit) = split_number(s)?;
+        if !unit.is_empty() {
+            return Err(ParseQuantityError::UnknownUnit {
+                quantity: "Coef",
+                unit: unit.to_string(),
+            });
+        }
+        Ok(Coef::new(v))
+    }
+}
+
+// 接受 "[1, 2, 3] nT"（公共单位）或 "[1 nT, 2 μT, 3 nT]"（逐个分量带单位），方括号也可以换成圆括号
+impl<T> FromStr for Vector3<T>
+where
+    T: VectorQuantity + Default + FromStr<Err = ParseQuantityError>,
+{
+    type Err = ParseQuantityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let invalid = || ParseQuantityError::InvalidVector(s.to_string());
+        let close = match s.chars().next() {
+            Some('[') => ']',
+            Some('(') => ')',
+            _ => return Err(invalid()),
+        };
+        let end = s.find(close).ok_or_else(invalid)?;
+        let unit = s[end + close.len_utf8()..].trim();
+        let parts: Vec<&str> = s[1..end].split(',').collect();
+        if parts.len() != 3 {
+            return Err(invalid());
+        }
+        let component = |part: &str| {
+            if unit.is_empty() {
+                part.parse::<T>()
+            } else {
+                format!("{} {}", part.trim(), unit).parse::<T>()
+            }
+        };
+        Ok(Vector3::new(
+            component(parts[0])?,
+            component(parts[1])?,
+            component(parts[2])?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_parse_enum_units() {
+        let v: Velocity = "12.5 km/h".parse().unwrap();
+        assert_eq!(v, Velocity::from_km_per_h(12.5));
+        assert_eq!(
+            "3 mN·m".parse::<Torque>().unwrap(),
+            Torque::from_mill_nm(3.0)
+        );
+        assert_eq!(
+            "3mN*m".parse::<Torque>().unwrap(),
+            Torque::from_mill_nm(3.0)
+        );
+        assert_eq!(
+            "-40 nT".parse::<MagneticInduction>().unwrap(),
+            MagneticInduction::from_nano_tesla(-40.0)
+        );
+        assert_eq!(
+            "2 μT".parse::<MagneticInduction>().unwrap(),
+            MagneticInduction::from_micro_tesla(2.0)
+        );
+        assert_eq!(
+            "2 µT".parse::<MagneticInduction>().unwrap(),
+            MagneticInduction::from_micro_tesla(2.0)
+        );
+        assert_eq!("1e3 m²".parse::<Area>().unwrap(), Area::from_m2(1000.0));
+        assert_eq!("7 km^2".parse::<Area>().unwrap(), Area::from_km2(7.0));
+        assert_eq!(
+            "9.8 m/s2".parse::<Acceleration>().unwrap(),
+            Acceleration::from_m_per_s2(9.8)
+        );
+        assert_eq!(
+            "0.1 kg·m²/s".parse::<AngularMomentum>().unwrap(),
+            AngularMomentum::from_kg_m2_per_second(0.1)
+        );
+        assert_eq!("90°".parse::<Angular>().unwrap(), Angular::from_deg(90.0));
+        assert_eq!(
+            "5 MeV".parse::<Energy>().unwrap(),
+            Energy::from_mega_electron_volt(5.0)
+        );
+        assert_eq!("250 g".parse::<Mass>().unwrap(), Mass::from_g(250.0));
+        assert_eq!("0.5".parse::<Coef>().unwrap(), Coef::new(0.5));
+    }
+
+    #[test]
+    fn test_parse_si_prefix() {
+        let d: Distance = "5 mm".parse().unwrap();
+        assert_relative_eq!(d.as_m(), 0.005);
+        let p: Power = "2 GW".parse().unwrap();
+        assert_relative_eq!(p.as_watt(), 2e9);
+        let a: Angular = "10 mrad".parse().unwrap();
+        assert_relative_eq!(a.as_rad(), 0.01);
+        let m: Mass = "3 mg".parse().unwrap();
+        assert_relative_eq!(m.as_kg(), 3e-6);
+        let w: AngularVelocity = "4 μrad/s".parse().unwrap();
+        assert_relative_eq!(w.as_rad_per_second(), 4e-6);
+        let s: Area = "5 mm²".parse().unwrap();
+        assert_relative_eq!(s.as_m2(), 5e-6);
+        let v: Volume = "2 cm^3".parse().unwrap();
+        assert_relative_eq!(v.as_m3(), 2e-6);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("".parse::<Distance>(), Err(ParseQuantityError::Empty));
+        assert_eq!(
+            "km".parse::<Distance>(),
+            Err(ParseQuantityError::InvalidNumber("km".to_string()))
+        );
+        assert_eq!(
+            "12".parse::<Distance>(),
+            Err(ParseQuantityError::MissingUnit {
+                quantity: "Distance"
+            })
+        );
+        assert_eq!(
+            "12 kg".parse::<Distance>(),
+            Err(ParseQuantityError::UnknownUnit {
+                quantity: "Distance",
+                unit: "kg".to_string()
+            })
+        );
+        assert!("1 m".parse::<Coef>().is_err());
+        // 带偏移的温度与没有词头基准的物理量不接受词头
+        assert_eq!(
+            "1 m°C".parse::<Temperature>(),
+            Err(ParseQuantityError::UnknownUnit {
+                quantity: "Temperature",
+                unit: "m°C".to_string()
+            })
+        );
+        assert_eq!(
+            "5 k°F".parse::<Temperature>(),
+            Err(ParseQuantityError::UnknownUnit {
+                quantity: "Temperature",
+                unit: "k°F".to_string()
+            })
+        );
+        assert!("1 mK".parse::<Temperature>().is_err());
+        // 词头只能加在基准单位上
+        assert_eq!(
+            "1 mkm".parse::<Distance>(),
+            Err(ParseQuantityError::UnknownUnit {
+                quantity: "Distance",
+                unit: "mkm".to_string()
+            })
+        );
+        assert!("1 kly".parse::<Distance>().is_err());
+        assert!("2 kkW".parse::<Power>().is_err());
+        assert!("1 m°C".parse::<TemperatureDifference>().is_err());
+        assert!("1 m°".parse::<Angular>().is_err());
+        assert_eq!(
+            "3 mK".parse::<TemperatureDifference>().unwrap(),
+            TemperatureDifference::from_kelvin(0.003)
+        );
+        assert!("2 mkg·m/s".parse::<Momentum>().is_err());
+        assert_eq!(
+            "3 mN·m".parse::<Force>().unwrap_err().to_string(),
+            "unknown unit 'mN·m' for Force"
+        );
+    }
+
+    #[test]
+    fn test_parse_vector3() {
+        let b: Vector3<MagneticInduction> = "[1.0, 2.0, 3.0] nT".parse().unwrap();
+        assert_eq!(b.y, MagneticInduction::from_nano_tesla(2.0));
+        let d: Vector3<Distance> = "(1 m, 2 km, -3 m)".parse().unwrap();
+        assert_eq!(d.y, Distance::from_km(2.0));
+        assert_eq!(d.z, Distance::from_m(-3.0));
+        assert!("[1, 2] nT".parse::<Vector3<MagneticInduction>>().is_err());
+        assert!("1, 2, 3 nT".parse::<Vector3<MagneticInduction>>().is_err());
+        assert!(matches!(
+            "[1, 2, 3] kg".parse::<Vector3<Distance>>(),
+            Err(ParseQuantityError::UnknownUnit { .. })
+        ));
+    }
+}
@@ -0,0 +1,430 @@
+use crate::physics::basic::{
+    Coef, PhysicalQuantity, Temperature, TemperatureDifference, TemperatureType,
+};
+use std::any::Any;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+// 0 °C 与 0 °F 对应的开尔文温度
+const CELSIUS_ZERO: f64 = 273.15;
+const FAHRENHEIT_ZERO: f64 = 459.67 * 5.0 / 9.0;
+
+impl Default for Temperature {
+    fn default() -> Self {
+        Self::from_kelvin(0.0)
+    }
+}
+
+impl PhysicalQuantity for Temperature {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    // 绝对零度
+    fn is_zero(&self) -> bool {
+        self.as_kelvin() == 0.0
+    }
+
+    fn default_unit_value(&self) -> f64 {
+        self.as_kelvin()
+    }
+
+    fn set_value(&mut self, value: f64) {
+        self.v = value;
+    }
+}
+
+impl Temperature {
+    pub fn from_kelvin(kelvin: f64) -> Self {
+        Self {
+            default_type: TemperatureType::Kelvin,
+            v: kelvin,
+        }
+    }
+
+    pub fn from_celsius(celsius: f64) -> Self {
+        Self {
+            default_type: TemperatureType::Celsius,
+            v: celsius,
+        }
+    }
+
+    pub fn from_fahrenheit(fahrenheit: f64) -> Self {
+        Self {
+            default_type: TemperatureType::Fahrenheit,
+            v: fahrenheit,
+        }
+    }
+
+    pub fn as_kelvin(&self) -> f64 {
+        match self.default_type {
+            TemperatureType::Kelvin => self.v,
+            TemperatureType::Celsius => self.v + CELSIUS_ZERO,
+            TemperatureType::Fahrenheit => self.v * 5.0 / 9.0 + FAHRENHEIT_ZERO,
+        }
+    }
+
+    pub fn as_celsius(&self) -> f64 {
+        match self.default_type {
+            TemperatureType::Celsius => self.v,
+            TemperatureType::Kelvin => self.v - CELSIUS_ZERO,
+            TemperatureType::Fahrenheit => (self.v - 32.0) * 5.0 / 9.0,
+        }
+    }
+
+    pub fn as_fahrenheit(&self) -> f64 {
+        match self.default_type {
+            TemperatureType::Fahrenheit => self.v,
+            TemperatureType::Celsius => self.v * 9.0 / 5.0 + 32.0,
+            TemperatureType::Kelvin => (self.v - FAHRENHEIT_ZERO) * 9.0 / 5.0,
+        }
+    }
+
+    fn value_in(&self, unit: TemperatureType) -> f64 {
+        match unit {
+            TemperatureType::Kelvin => self.as_kelvin(),
+            TemperatureType::Celsius => self.as_celsius(),
+            TemperatureType::Fahrenheit => self.as_fahrenheit(),
+        }
+    }
+}
+
+impl Default for TemperatureDifference {
+    fn default() -> Self {
+        Self::from_kelvin(0.0)
+    }
+}
+
+impl PhysicalQuantity for TemperatureDifference {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_zero(&self) -> bool {
+        self.v == 0.0
+    }
+
+    fn default_unit_value(&self) -> f64 {
+        self.as_kelvin()
+    }
+
+    fn set_value(&mut self, value: f64) {
+        self.v = value;
+    }
+}
+
+impl TemperatureDifference {
+    pub fn from_kelvin(kelvin: f64) -> Self {
+        Self {
+            default_type: TemperatureType::Kelvin,
+            v: kelvin,
+        }
+    }
+
+    pub fn from_celsius(celsius: f64) -> Self {
+        Self {
+            default_type: TemperatureType::Celsius,
+            v: celsius,
+        }
+    }
+
+    pub fn from_fahrenheit(fahrenheit: f64) -> Self {
+        Self {
+            default_type: TemperatureType::Fahrenheit,
+            v: fahrenheit,
+        }
+    }
+
+    pub fn as_kelvin(&self) -> f64 {
+        match self.default_type {
+            TemperatureType::Kelvin | TemperatureType::Celsius => self.v,
+            TemperatureType::Fahrenheit => self.v * 5.0 / 9.0,
+        }
+    }
+
+    // 摄氏度温差与开尔文温差相等
+    pub fn as_celsius(&self) -> f64 {
+        self.as_kelvin()
+    }
+
+    pub fn as_fahrenheit(&self) -> f64 {
+        match self.default_type {
+            TemperatureType::Fahrenheit => self.v,
+            _ => self.v * 9.0 / 5.0,
+        }
+    }
+
+    pub fn abs(&self) -> Self {
+        Self {
+            default_type: self.default_type,
+            v: self.v.abs(),
+        }
+    }
+
+    fn value_in(&self, unit: TemperatureType) -> f64 {
+        match unit {
+            TemperatureType::Kelvin => self.as_kelvin(),
+            TemperatureType::Celsius => self.as_celsius(),
+            TemperatureType::Fahrenheit => self.as_fahrenheit(),
+        }
+    }
+}
+
+// 温度 - 温度 = 温差，结果使用左侧温度的单位
+impl Sub for Temperature {
+    type Output = TemperatureDifference;
+    fn sub(self, rhs: Self) -> Self::Output {
+        TemperatureDifference {
+            default_type: self.default_type,
+            v: self.v - rhs.value_in(self.default_type),
+        }
+    }
+}
+
+// 引用版本：Temperature - Temperature -> TemperatureDifference
+impl Sub<&Temperature> for &Temperature {
+    type Output = TemperatureDifference;
+    fn sub(self, rhs: &Temperature) -> Self::Output {
+        *self - *rhs
+    }
+}
+impl Sub<&Temperature> for Temperature {
+    type Output = TemperatureDifference;
+    fn sub(self, rhs: &Temperature) -> Self::Output {
+        self - *rhs
+    }
+}
+impl Sub<Temperature> for &Temperature {
+    type Output = TemperatureDifference;
+    fn sub(self, rhs: Temperature) -> Self::Output {
+        *self - rhs
+    }
+}
+
+// 温度 ± 温差 = 温度，保持温度原来的单位
+impl Add<TemperatureDifference> for Temperature {
+    type Output = Temperature;
+    fn add(self, rhs: TemperatureDifference) -> Self::Output {
+        Temperature {
+            default_type: self.default_type,
+            v: self.v + rhs.value_in(self.default_type),
+        }
+    }
+}
+
+impl Add<Temperature> for TemperatureDifference {
+    type Output = Temperature;
+    fn add(self, rhs: Temperature) -> Self::Output {
+        rhs + self
+    }
+}
+
+impl Sub<TemperatureDifference> for Temperature {
+    type Output = Temperature;
+    fn sub(self, rhs: TemperatureDifference) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Add for TemperatureDifference {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        TemperatureDifference {
+            default_type: self.default_type,
+            v: self.v + rhs.value_in(self.default_type),
+        }
+    }
+}
+
+impl Sub for TemperatureDifference {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Mul<f64> for TemperatureDifference {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self::Output {
+        TemperatureDifference {
+            default_type: self.default_type,
+            v: self.v * rhs,
+        }
+    }
+}
+
+impl Div<f64> for TemperatureDifference {
+    type Output = Self;
+    fn div(self, rhs: f64) -> Self::Output {
+        TemperatureDifference {
+            default_type: self.default_type,
+            v: self.v / rhs,
+        }
+    }
+}
+
+impl Mul<TemperatureDifference> for f64 {
+    type Output = TemperatureDifference;
+    fn mul(self, rhs: TemperatureDifference) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<Coef> for TemperatureDifference {
+    type Output = Self;
+    fn mul(self, rhs: Coef) -> Self::Output {
+        self * rhs.get_value()
+    }
+}
+
+impl Div<Coef> for TemperatureDifference {
+    type Output = Self;
+    fn div(self, rhs: Coef) -> Self::Output {
+        self / rhs.get_value()
+    }
+}
+
+// 温差之比
+impl Div for TemperatureDifference {
+    type Output = Coef;
+    fn div(self, rhs: Self) -> Self::Output {
+        Coef::new(self.as_kelvin() / rhs.as_kelvin())
+    }
+}
+
+impl Neg for TemperatureDifference {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        TemperatureDifference {
+            default_type: self.default_type,
+            v: -self.v,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_absolute_conversion() {
+        let t = Temperature::from_celsius(100.0);
+        assert_relative_eq!(t.as_kelvin(), 373.15);
+        assert_relative_eq!(t.as_fahrenheit(), 212.0, epsilon = 1e-12);
+        let f = Temperature::from_fahrenheit(-40.0);
+        assert_relative_eq!(f.as_celsius(), -40.0);
+        assert_relative_eq!(f.as_kelvin(), 233.15, epsilon = 1e-12);
+        let k = Temperature::from_kelvin(0.0);
+        assert_relative_eq!(k.as_celsius(), -273.15);
+        assert_relative_eq!(k.as_fahrenheit(), -459.67, epsilon = 1e-12);
+        assert!(k.is_zero());
+        assert!(!Temperature::from_celsius(0.0).is_zero());
+        assert_relative_eq!(
+            Temperature::from_fahrenheit(32.0).default_unit_value(),
+            273.15,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_difference_has_no_offset() {
+        let d = TemperatureDifference::from_celsius(10.0);
+        assert_eq!(d.as_kelvin(), 10.0);
+        assert_relative_eq!(d.as_fahrenheit(), 18.0);
+        assert_relative_eq!(TemperatureDifference::from_fahrenheit(9.0).as_kelvin(), 5.0);
+        assert_relative_eq!(
+            TemperatureDifference::from_fahrenheit(9.0).as_celsius(),
+            5.0
+        );
+        assert_eq!(
+            TemperatureDifference::from_kelvin(-2.0).abs(),
+            TemperatureDifference::from_kelvin(2.0)
+        );
+    }
+
+    #[test]
+    fn test_affine_arithmetic() {
+        // 温度相减得到温差，偏移相互抵消
+        let dt = Temperature::from_celsius(25.0) - Temperature::from_celsius(20.0);
+        assert_eq!(dt, TemperatureDifference::from_celsius(5.0));
+        let dt = Temperature::from_celsius(20.0) - Temperature::from_kelvin(283.15);
+        assert_relative_eq!(dt.as_kelvin(), 10.0, epsilon = 1e-12);
+        let dt = &Temperature::from_fahrenheit(50.0) - &Temperature::from_celsius(0.0);
+        assert_relative_eq!(dt.as_fahrenheit(), 18.0, epsilon = 1e-12);
+
+        // 温度加温差不引入偏移
+        let t = Temperature::from_celsius(20.0) + TemperatureDifference::from_kelvin(5.0);
+        assert_eq!(t, Temperature::from_celsius(25.0));
+        let t = Temperature::from_fahrenheit(68.0) + TemperatureDifference::from_celsius(10.0);
+        assert_relative_eq!(t.as_fahrenheit(), 86.0);
+        let t = Temperature::from_kelvin(300.0) - TemperatureDifference::from_fahrenheit(18.0);
+        assert_relative_eq!(t.as_kelvin(), 290.0);
+        assert_eq!(
+            TemperatureDifference::from_kelvin(1.0) + Temperature::from_kelvin(1.0),
+            Temperature::from_kelvin(2.0)
+        );
+    }
+
+    #[test]
+    fn test_difference_arithmetic() {
+        let a = TemperatureDifference::from_celsius(3.0);
+        let b = TemperatureDifference::from_fahrenheit(9.0);
+        assert_relative_eq!((a + b).as_kelvin(), 8.0);
+        assert_relative_eq!((a - b).as_kelvin(), -2.0);
+        assert_relative_eq!((a * 2.0).as_celsius(), 6.0);
+        assert_relative_eq!((2.0 * a).as_celsius(), 6.0);
+        assert_relative_eq!((a / 3.0).as_kelvin(), 1.0);
+        assert_relative_eq!((a * Coef::new(0.5)).as_kelvin(), 1.5);
+        assert_relative_eq!((a / Coef::new(0.5)).as_kelvin(), 6.0);
+        assert_relative_eq!((b / a).get_value(), 5.0 / 3.0);
+        assert_eq!(-a, TemperatureDifference::from_celsius(-3.0));
+    }
+
+    #[test]
+    fn test_temperature_text() {
+        assert_eq!(
+            "20 °C".parse::<Temperature>().unwrap(),
+            Temperature::from_celsius(20.0)
+        );
+        assert_eq!(
+            "-40℉".parse::<Temperature>().unwrap(),
+            Temperature::from_fahrenheit(-40.0)
+        );
+        assert_eq!(
+            "5 K".parse::<TemperatureDifference>().unwrap(),
+            TemperatureDifference::from_kelvin(5.0)
+        );
+        assert_eq!(Temperature::from_celsius(21.5).to_string(), "21.5 °C");
+        let t = Temperature::from_celsius(20.0);
+        assert_eq!(
+            t.formatted()
+                .unit(TemperatureType::Kelvin)
+                .precision(2)
+                .to_string(),
+            "293.15 K"
+        );
+        assert_eq!(
+            t.formatted()
+                .unit(TemperatureType::Fahrenheit)
+                .precision(1)
+                .to_string(),
+            "68.0 °F"
+        );
+        // 温差换算不带偏移
+        let d = TemperatureDifference::from_celsius(20.0);
+        assert_eq!(
+            d.formatted()
+                .unit(TemperatureType::Fahrenheit)
+                .precision(1)
+                .to_string(),
+            "36.0 °F"
+        );
+        assert_eq!(
+            TemperatureDifference::from_kelvin(0.002)
+                .formatted()
+                .auto_prefix()
+                .to_string(),
+            "2 mK"
+        );
+    }
+}
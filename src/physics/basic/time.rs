@@ -0,0 +1,478 @@
+use crate::physics::basic::{
+    Acceleration, Angular, AngularAcceleration, AngularVelocity, Coef, Distance, Energy,
+    PhysicalQuantity, Power, Time, TimeType, Vector3, Velocity,
+};
+use std::any::Any;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::time::{Duration, TryFromFloatSecsError};
+
+impl Default for Time {
+    fn default() -> Self {
+        Self::from_second(0.0)
+    }
+}
+
+impl PhysicalQuantity for Time {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_zero(&self) -> bool {
+        self.v == 0.0
+    }
+
+    fn default_unit_value(&self) -> f64 {
+        self.as_second()
+    }
+
+    fn set_value(&mut self, value: f64) {
+        self.v = value;
+    }
+}
+
+impl Time {
+    pub fn from_second(second: f64) -> Self {
+        Self {
+            default_type: TimeType::Second,
+            v: second,
+        }
+    }
+
+    pub fn from_mill_second(mill_second: f64) -> Self {
+        Self {
+            default_type: TimeType::MillSecond,
+            v: mill_second,
+        }
+    }
+
+    pub fn from_micro_second(micro_second: f64) -> Self {
+        Self {
+            default_type: TimeType::MicroSecond,
+            v: micro_second,
+        }
+    }
+
+    pub fn from_minute(minute: f64) -> Self {
+        Self {
+            default_type: TimeType::Minute,
+            v: minute,
+        }
+    }
+
+    pub fn from_hour(hour: f64) -> Self {
+        Self {
+            default_type: TimeType::Hour,
+            v: hour,
+        }
+    }
+
+    pub fn from_day(day: f64) -> Self {
+        Self {
+            default_type: TimeType::Day,
+            v: day,
+        }
+    }
+
+    pub fn as_second(&self) -> f64 {
+        match self.default_type {
+            TimeType::Second => self.v,
+            TimeType::MillSecond => self.v * 1e-3,
+            TimeType::MicroSecond => self.v * 1e-6,
+            TimeType::Minute => self.v * 60.0,
+            TimeType::Hour => self.v * 3600.0,
+            TimeType::Day => self.v * 86400.0,
+        }
+    }
+
+    pub fn as_mill_second(&self) -> f64 {
+        match self.default_type {
+            TimeType::MillSecond => self.v,
+            _ => self.as_second() * 1e3,
+        }
+    }
+
+    pub fn as_micro_second(&self) -> f64 {
+        match self.default_type {
+            TimeType::MicroSecond => self.v,
+            _ => self.as_second() * 1e6,
+        }
+    }
+
+    pub fn as_minute(&self) -> f64 {
+        match self.default_type {
+            TimeType::Minute => self.v,
+            _ => self.as_second() / 60.0,
+        }
+    }
+
+    pub fn as_hour(&self) -> f64 {
+        match self.default_type {
+            TimeType::Hour => self.v,
+            _ => self.as_second() / 3600.0,
+        }
+    }
+
+    pub fn as_day(&self) -> f64 {
+        match self.default_type {
+            TimeType::Day => self.v,
+            _ => self.as_second() / 86400.0,
+        }
+    }
+
+    pub fn abs(&self) -> Self {
+        Self {
+            default_type: self.default_type,
+            v: self.v.abs(),
+        }
+    }
+}
+
+impl From<Duration> for Time {
+    fn from(duration: Duration) -> Self {
+        Time::from_second(duration.as_secs_f64())
+    }
+}
+
+// 负数、NaN 或超出 Duration 范围时失败
+impl TryFrom<Time> for Duration {
+    type Error = TryFromFloatSecsError;
+    fn try_from(time: Time) -> Result<Self, Self::Error> {
+        Duration::try_from_secs_f64(time.as_second())
+    }
+}
+
+impl Add for Time {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        let v = self.as_second() + rhs.as_second();
+        Self::from_second(v)
+    }
+}
+
+// 引用-引用 与 混合引用：Time 加法
+impl Add<&Time> for &Time {
+    type Output = Time;
+    fn add(self, rhs: &Time) -> Self::Output {
+        Time::from_second(self.as_second() + rhs.as_second())
+    }
+}
+impl Add<&Time> for Time {
+    type Output = Time;
+    fn add(self, rhs: &Time) -> Self::Output {
+        Time::from_second(self.as_second() + rhs.as_second())
+    }
+}
+impl Add<Time> for &Time {
+    type Output = Time;
+    fn add(self, rhs: Time) -> Self::Output {
+        Time::from_second(self.as_second() + rhs.as_second())
+    }
+}
+
+impl Add<f64> for Time {
+    type Output = Self;
+    fn add(self, rhs: f64) -> Self::Output {
+        Time {
+            v: self.v + rhs,
+            default_type: self.default_type,
+        }
+    }
+}
+
+impl Sub for Time {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        let v = self.as_second() - rhs.as_second();
+        Self::from_second(v)
+    }
+}
+
+// 引用-引用 与 混合引用：Time 减法
+impl Sub<&Time> for &Time {
+    type Output = Time;
+    fn sub(self, rhs: &Time) -> Self::Output {
+        Time::from_second(self.as_second() - rhs.as_second())
+    }
+}
+impl Sub<&Time> for Time {
+    type Output = Time;
+    fn sub(self, rhs: &Time) -> Self::Output {
+        Time::from_second(self.as_second() - rhs.as_second())
+    }
+}
+impl Sub<Time> for &Time {
+    type Output = Time;
+    fn sub(self, rhs: Time) -> Self::Output {
+        Time::from_second(self.as_second() - rhs.as_second())
+    }
+}
+
+impl Sub<f64> for Time {
+    type Output = Self;
+    fn sub(self, rhs: f64) -> Self::Output {
+        Time {
+            v: self.v - rhs,
+            default_type: self.default_type,
+        }
+    }
+}
+
+impl Mul<f64> for Time {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Time {
+            v: self.v * rhs,
+            default_type: self.default_type,
+        }
+    }
+}
+
+impl Div<f64> for Time {
+    type Output = Self;
+    fn div(self, rhs: f64) -> Self::Output {
+        Time {
+            v: self.v / rhs,
+            default_type: self.default_type,
+        }
+    }
+}
+
+impl Mul<Time> for f64 {
+    type Output = Time;
+    fn mul(self, rhs: Time) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<Coef> for Time {
+    type Output = Self;
+    fn mul(self, rhs: Coef) -> Self::Output {
+        self * rhs.get_value()
+    }
+}
+
+impl Div<Coef> for Time {
+    type Output = Self;
+    fn div(self, rhs: Coef) -> Self::Output {
+        self / rhs.get_value()
+    }
+}
+
+// 时间之比
+impl Div for Time {
+    type Output = Coef;
+    fn div(self, rhs: Self) -> Self::Output {
+        Coef::new(self.as_second() / rhs.as_second())
+    }
+}
+
+impl Neg for Time {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Time {
+            v: -self.v,
+            default_type: self.default_type,
+        }
+    }
+}
+
+// 与 Duration 相同的运算关系，t 为秒数；同时生成引用版本
+macro_rules! impl_time_ops {
+    ($($lhs:ty, $op:ident, $method:ident => $output:ty, |$q:ident, $t:ident| $body:expr;)+) => {
+        $(
+            impl $op<Time> for $lhs {
+                type Output = $output;
+                fn $method(self, rhs: Time) -> Self::Output {
+                    let ($q, $t) = (&self, rhs.as_second());
+                    $body
+                }
+            }
+            impl $op<Time> for &$lhs {
+                type Output = $output;
+                fn $method(self, rhs: Time) -> Self::Output {
+                    let ($q, $t) = (self, rhs.as_second());
+                    $body
+                }
+            }
+            impl $op<&Time> for $lhs {
+                type Output = $output;
+                fn $method(self, rhs: &Time) -> Self::Output {
+                    let ($q, $t) = (&self, rhs.as_second());
+                    $body
+                }
+            }
+            impl $op<&Time> for &$lhs {
+                type Output = $output;
+                fn $method(self, rhs: &Time) -> Self::Output {
+                    let ($q, $t) = (self, rhs.as_second());
+                    $body
+                }
+            }
+        )+
+    };
+}
+
+impl_time_ops! {
+    Distance, Div, div => Velocity, |d, t| Velocity::from_m_per_sec(d.as_m() / t);
+    Velocity, Mul, mul => Distance, |v, t| Distance::from_m(v.as_m_per_sec() * t);
+    Velocity, Div, div => Acceleration, |v, t| Acceleration::from_m_per_s2(v.as_m_per_sec() / t);
+    Acceleration, Mul, mul => Velocity, |a, t| Velocity::from_m_per_sec(a.as_m_per_s2() * t);
+    Angular, Div, div => AngularVelocity,
+        |a, t| AngularVelocity::from_rad_per_second(a.as_rad() / t);
+    AngularVelocity, Mul, mul => Angular, |w, t| Angular::from_rad(w.as_rad_per_second() * t);
+    AngularVelocity, Div, div => AngularAcceleration,
+        |w, t| AngularAcceleration::from_rad_per_second2(w.as_rad_per_second() / t);
+    AngularAcceleration, Mul, mul => AngularVelocity,
+        |a, t| AngularVelocity::from_rad_per_second(a.as_rad_per_second2() * t);
+    Coef, Mul, mul => Coef, |c, t| Coef::new(c.get_value() * t);
+    Energy, Div, div => Power, |e, t| Power::from_watt(e.as_joule() / t);
+    Power, Mul, mul => Energy, |p, t| Energy::from_joule(p.as_watt() * t);
+}
+
+// 时间与功率的乘积（得到能量，满足交换律）
+impl Mul<Power> for Time {
+    type Output = Energy;
+    fn mul(self, rhs: Power) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<Time> for Vector3<AngularVelocity> {
+    type Output = Vector3<Angular>;
+    fn mul(self, rhs: Time) -> Self::Output {
+        Vector3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Div<Time> for Vector3<Distance> {
+    type Output = Vector3<Velocity>;
+    fn div(self, rhs: Time) -> Self::Output {
+        Vector3::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_time_units() {
+        assert_relative_eq!(Time::from_mill_second(1500.0).as_second(), 1.5);
+        assert_relative_eq!(Time::from_micro_second(2.0).as_second(), 2e-6);
+        assert_relative_eq!(Time::from_minute(1.5).as_second(), 90.0);
+        assert_relative_eq!(Time::from_hour(2.0).as_minute(), 120.0);
+        assert_relative_eq!(Time::from_day(1.0).as_hour(), 24.0);
+        assert_relative_eq!(Time::from_second(0.25).as_mill_second(), 250.0);
+        assert_relative_eq!(Time::from_second(3.0).as_micro_second(), 3e6);
+        assert_relative_eq!(Time::from_hour(36.0).as_day(), 1.5);
+        assert_eq!(Time::from_minute(7.0).as_minute(), 7.0);
+        assert_relative_eq!(Time::from_day(0.5).default_unit_value(), 43200.0);
+        assert!(Time::default().is_zero());
+    }
+
+    #[test]
+    fn test_time_arithmetic() {
+        let t = Time::from_second(1.0) + Time::from_mill_second(500.0);
+        assert_relative_eq!(t.as_second(), 1.5);
+        assert_relative_eq!((&t - &Time::from_second(2.0)).as_second(), -0.5);
+        assert_relative_eq!((Time::from_minute(2.0) * 1.5).as_minute(), 3.0);
+        assert_relative_eq!((2.0 * Time::from_hour(1.0)).as_hour(), 2.0);
+        assert_relative_eq!((Time::from_hour(1.0) / 4.0).as_minute(), 15.0);
+        assert_relative_eq!((Time::from_second(6.0) * Coef::new(0.5)).as_second(), 3.0);
+        assert_relative_eq!(
+            (Time::from_hour(1.0) / Time::from_minute(1.0)).get_value(),
+            60.0
+        );
+        assert_eq!(-Time::from_mill_second(3.0), Time::from_mill_second(-3.0));
+        assert_eq!(Time::from_second(-2.0).abs(), Time::from_second(2.0));
+        assert_relative_eq!((Time::from_minute(1.0) + 1.0).as_minute(), 2.0);
+    }
+
+    #[test]
+    fn test_duration_conversion() {
+        let t = Time::from(Duration::from_millis(1500));
+        assert_relative_eq!(t.as_second(), 1.5);
+        assert_eq!(
+            Duration::try_from(Time::from_mill_second(250.0)),
+            Ok(Duration::from_millis(250))
+        );
+        assert!(Duration::try_from(Time::from_second(-1.0)).is_err());
+        assert!(Duration::try_from(Time::from_second(f64::NAN)).is_err());
+    }
+
+    #[test]
+    fn test_time_operators_match_duration() {
+        let t = Time::from_second(2.0);
+        let d = Duration::from_secs(2);
+        assert_eq!(Distance::from_m(10.0) / t, Distance::from_m(10.0) / d);
+        assert_eq!(
+            Velocity::from_m_per_sec(3.0) * t,
+            Velocity::from_m_per_sec(3.0) * d
+        );
+        assert_eq!(
+            Velocity::from_m_per_sec(3.0) / t,
+            Velocity::from_m_per_sec(3.0) / d
+        );
+        assert_eq!(Acceleration::from_g(1.0) * t, Acceleration::from_g(1.0) * d);
+        assert_eq!(Angular::from_deg(90.0) / t, Angular::from_deg(90.0) / d);
+        assert_eq!(
+            AngularVelocity::from_rad_per_second(1.0) * t,
+            AngularVelocity::from_rad_per_second(1.0) * d
+        );
+        assert_eq!(
+            AngularVelocity::from_rad_per_second(1.0) / t,
+            AngularVelocity::from_rad_per_second(1.0) / d
+        );
+        let alpha = AngularAcceleration::from_rad_per_second2(4.0);
+        assert_eq!(alpha * t, alpha * d);
+        assert_eq!(Coef::new(3.0) * t, Coef::new(3.0) * d);
+        assert_eq!(Energy::from_joule(500.0) / t, Energy::from_joule(500.0) / d);
+        assert_eq!(Power::from_watt(100.0) * t, Power::from_watt(100.0) * d);
+        assert_eq!(t * Power::from_watt(100.0), d * Power::from_watt(100.0));
+        assert_eq!(&Velocity::from_m_per_sec(3.0) * &t, Distance::from_m(6.0));
+
+        let w = Vector3::new(
+            AngularVelocity::from_rad_per_second(1.0),
+            AngularVelocity::from_rad_per_second(2.0),
+            AngularVelocity::from_rad_per_second(3.0),
+        );
+        assert_eq!(w * t, w * d);
+        let r = Vector3::new(
+            Distance::from_m(2.0),
+            Distance::from_m(4.0),
+            Distance::from_m(6.0),
+        );
+        assert_eq!(r / t, r / d);
+    }
+
+    #[test]
+    fn test_time_text_and_dimension() {
+        use crate::physics::basic::Dimensional;
+        assert_eq!(
+            "20 ms".parse::<Time>().unwrap(),
+            Time::from_mill_second(20.0)
+        );
+        assert_eq!("1.5 h".parse::<Time>().unwrap(), Time::from_hour(1.5));
+        assert_eq!(Time::from_micro_second(-3.0).to_string(), "-3 μs");
+        assert_eq!(
+            Time::from_second(0.02)
+                .formatted()
+                .auto_prefix()
+                .to_string(),
+            "20 ms"
+        );
+        let v: Velocity = (Distance::from_m(10.0).quantity() / Time::from_second(4.0)).into();
+        assert_relative_eq!(v.as_m_per_sec(), 2.5);
+    }
+
+    #[test]
+    fn test_negative_and_sub_nanosecond_steps() {
+        // 倒推与亚纳秒步长，Duration 无法表示
+        let back = Velocity::from_m_per_sec(5.0) * Time::from_mill_second(-20.0);
+        assert_relative_eq!(back.as_m(), -0.1);
+        let dt = Time::from_second(1e-12);
+        let dv = Acceleration::from_m_per_s2(2.0) * dt;
+        assert_relative_eq!(dv.as_m_per_sec(), 2e-12);
+    }
+}
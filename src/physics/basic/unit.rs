@@ -0,0 +1,264 @@
+use super::format::QuantityFormat;
+use super::parse::{self, ParseQuantityError};
+use super::*;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// 物理量的单位枚举（`TorqueType`、`MagneticInductionType` 等）
+pub trait UnitSymbol: Copy + PartialEq + 'static {
+    // 该单位可识别的符号，第一个为标准写法
+    fn symbols(&self) -> &'static [&'static str];
+
+    // 全部单位
+    fn units() -> &'static [Self];
+
+    fn symbol(&self) -> &'static str {
+        self.symbols()[0]
+    }
+}
+
+/// 以某个单位枚举保存数值的物理量
+pub trait UnitQuantity: PhysicalQuantity + Sized {
+    type Unit: UnitSymbol;
+    const NAME: &'static str;
+    // 自动选择词头时所用的基准单位，为 None 时不加词头
+    const PREFIX_BASE: Option<Self::Unit>;
+
+    fn with_unit(unit: Self::Unit, v: f64) -> Self;
+
+    // 构造时使用的单位
+    fn unit(&self) -> Self::Unit;
+
+    // 以 unit() 为单位的数值
+    fn value(&self) -> f64;
+}
+
+// 物理量与单位枚举的对应关系、Display 与 FromStr
+macro_rules! impl_unit_quantity {
+    (@base $unit:ident) => {
+        None
+    };
+    (@base $unit:ident $base:ident) => {
+        Some($unit::$base)
+    };
+    ($quantity:ident: $unit:ident $([$base:ident])?) => {
+        impl UnitQuantity for $quantity {
+            type Unit = $unit;
+            const NAME: &'static str = stringify!($quantity);
+            const PREFIX_BASE: Option<$unit> = impl_unit_quantity!(@base $unit $($base)?);
+
+            fn with_unit(unit: $unit, v: f64) -> Self {
+                $quantity { default_type: unit, v }
+            }
+
+            fn unit(&self) -> $unit {
+                self.default_type
+            }
+
+            fn value(&self) -> f64 {
+                self.v
+            }
+        }
+
+        impl $quantity {
+            // 可指定输出单位、自动词头与精度
+            pub fn formatted(&self) -> QuantityFormat<'_, Self> {
+                QuantityFormat::new(self)
+            }
+        }
+
+        impl Display for $quantity {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                Display::fmt(&self.formatted(), f)
+            }
+        }
+
+        impl FromStr for $quantity {
+            type Err = ParseQuantityError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                parse::parse_quantity(s)
+            }
+        }
+    };
+}
+
+macro_rules! impl_units {
+    ($($quantity:ident: $unit:ident $([$base:ident])? { $($variant:ident => [$($symbol:literal),+],)+ })+) => {
+        $(
+            impl UnitSymbol for $unit {
+                fn symbols(&self) -> &'static [&'static str] {
+                    match self {
+                        $($unit::$variant => &[$($symbol),+],)+
+                    }
+                }
+
+                fn units() -> &'static [Self] {
+                    &[$($unit::$variant),+]
+                }
+            }
+
+            impl_unit_quantity!($quantity: $unit $([$base])?);
+        )+
+    };
+}
+
+// 物理量: 单位枚举 [自动词头的基准单位] { 单位 => [符号, ...] }
+impl_units! {
+    Distance: DistanceType [M] {
+        M => ["m"],
+        KM => ["km"],
+        LightYear => ["ly"],
+    }
+    Velocity: VelocityType [MPerSecond] {
+        MPerSecond => ["m/s"],
+        KmPerHour => ["km/h", "kph"],
+        KmPerSecond => ["km/s"],
+        LightSpeed => ["c"],
+    }
+    Acceleration: AccelerationType [MPerSecond2] {
+        MPerSecond2 => ["m/s²"],
+        KmPerHour2 => ["km/h²"],
+        G => ["g"],
+    }
+    Angular: AngularType [Rad] {
+        Rad => ["rad"],
+        Deg => ["°", "deg"],
+    }
+    AngularVelocity: AngularVelocityType [RadperSecond] {
+        RadperSecond => ["rad/s"],
+        DegPerSecond => ["°/s", "deg/s"],
+        RadperHour => ["rad/h"],
+        DegperHour => ["°/h", "deg/h"],
+    }
+    AngularAcceleration: AngularAccelerationType [RadperSecond2] {
+        RadperSecond2 => ["rad/s²"],
+        DegPerSecond2 => ["°/s²", "deg/s²"],
+    }
+    Mass: MassType [g] {
+        Kg => ["kg"],
+        g => ["g"],
+    }
+    AngularMomentum: AngularMomentumType [Nms] {
+        KgM2perSecond => ["kg·m²/s"],
+        KgKm2perSecond => ["kg·km²/s"],
+        Nms => ["N·m·s", "Nms"],
+        MillNms => ["mN·m·s", "mNms"],
+        MicroNms => ["μN·m·s", "uN·m·s", "μNms", "uNms"],
+        NanoNms => ["nN·m·s", "nNms"],
+    }
+    Momentum: MomentumType {
+        KgMperSecond => ["kg·m/s"],
+        KgKmperSecond => ["kg·km/s"],
+    }
+    Area: AreaType [M2] {
+        M2 => ["m²"],
+        KM2 => ["km²"],
+    }
+    MagneticInduction: MagneticInductionType [Tesla] {
+        Gauss => ["G", "Gs"],
+        Tesla => ["T"],
+        MillTesla => ["mT"],
+        MicroTesla => ["μT", "uT"],
+        NanoTesla => ["nT"],
+        MillGauss => ["mG"],
+        KiloGauss => ["kG"],
+    }
+    MagneticMoment: MagneticMomentType [AM2] {
+        AM2 => ["A·m²"],
+        MillAM2 => ["mA·m²"],
+        MicroAM2 => ["μA·m²", "uA·m²"],
+        NanoAM2 => ["nA·m²"],
+        JPerTesla => ["J/T"],
+        MillJPerTesla => ["mJ/T"],
+        MicroJPerTesla => ["μJ/T", "uJ/T"],
+        NanoJPerTesla => ["nJ/T"],
+    }
+    Torque: TorqueType [NM] {
+        NM => ["N·m", "Nm"],
+        MillNM => ["mN·m", "mNm"],
+        MicroNM => ["μN·m", "uN·m", "μNm", "uNm"],
+        NanoNM => ["nN·m", "nNm"],
+        KNM => ["kN·m", "kNm"],
+        MNM => ["MN·m", "MNm"],
+    }
+    Energy: EnergyType [Joule] {
+        Joule => ["J"],
+        MillJoule => ["mJ"],
+        MicroJoule => ["μJ", "uJ"],
+        NanoJoule => ["nJ"],
+        KiloJoule => ["kJ"],
+        MegaJoule => ["MJ"],
+        ElectronVolt => ["eV"],
+        KiloElectronVolt => ["keV"],
+        MegaElectronVolt => ["MeV"],
+    }
+    Force: ForceType [Newton] {
+        Newton => ["N"],
+        MillNewton => ["mN"],
+        MicroNewton => ["μN", "uN"],
+        NanoNewton => ["nN"],
+        KiloNewton => ["kN"],
+        MegaNewton => ["MN"],
+    }
+    Power: PowerType [Watt] {
+        Watt => ["W"],
+        MillWatt => ["mW"],
+        MicroWatt => ["μW", "uW"],
+        NanoWatt => ["nW"],
+        KiloWatt => ["kW"],
+        MegaWatt => ["MW"],
+        HorsePower => ["hp"],
+    }
+    MagneticAngularVelocity: MagneticAngularVelocityType [TeslaRadPerSecond] {
+        TeslaRadPerSecond => ["T·rad/s"],
+        MillTeslaRadPerSecond => ["mT·rad/s"],
+        MicroTeslaRadPerSecond => ["μT·rad/s", "uT·rad/s"],
+        NanoTeslaRadPerSecond => ["nT·rad/s"],
+        GaussRadPerSecond => ["G·rad/s"],
+        MillGaussRadPerSecond => ["mG·rad/s"],
+        KiloGaussRadPerSecond => ["kG·rad/s"],
+    }
+    Volume: VolumeType [M3] {
+        M3 => ["m³"],
+        KM3 => ["km³"],
+    }
+    Time: TimeType [Second] {
+        Second => ["s"],
+        MillSecond => ["ms"],
+        MicroSecond => ["μs", "us"],
+        Minute => ["min"],
+        Hour => ["h"],
+        Day => ["d", "day"],
+    }
+    HeatCapacity: HeatCapacityType [JoulePerKelvin] {
+        JoulePerKelvin => ["J/K"],
+        MillJoulePerKelvin => ["mJ/K"],
+        KiloJoulePerKelvin => ["kJ/K"],
+    }
+    // 绝对温度带偏移，不使用词头
+    Temperature: TemperatureType {
+        Kelvin => ["K"],
+        Celsius => ["°C", "℃", "degC"],
+        Fahrenheit => ["°F", "℉", "degF"],
+    }
+}
+
+// 温差与温度共用单位符号
+impl_unit_quantity!(TemperatureDifference: TemperatureType [Kelvin]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symbols_cover_all_units() {
+        assert_eq!(TorqueType::units().len(), 6);
+        assert_eq!(TorqueType::MillNM.symbol(), "mN·m");
+        assert_eq!(MagneticInductionType::MicroTesla.symbols(), &["μT", "uT"]);
+        for unit in MagneticInductionType::units() {
+            let s = format!("1 {}", unit.symbol());
+            assert_eq!(s.parse::<MagneticInduction>().unwrap().default_type, *unit);
+        }
+    }
+}
@@ -15,6 +15,7 @@ mod velocity;
 
 use super::*;
 use crate::dense::Matrix;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 use crate::utils::float;
 use std::ops::{Add, Mul, Neg, Sub};
 
@@ -599,6 +600,58 @@ where
     }
 }
 
+// 近似比较按各分量的国际单位制数值进行，不同单位表示的同一向量视为相等
+impl<T: VectorQuantity + Default> Vector3<T> {
+    fn default_unit_values(&self) -> [f64; 3] {
+        [
+            self.x.default_unit_value(),
+            self.y.default_unit_value(),
+            self.z.default_unit_value(),
+        ]
+    }
+}
+
+impl<T: VectorQuantity + Default + PartialEq> AbsDiffEq for Vector3<T> {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        f64::EPSILON
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.default_unit_values()
+            .iter()
+            .zip(other.default_unit_values().iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl<T: VectorQuantity + Default + PartialEq> RelativeEq for Vector3<T> {
+    fn default_max_relative() -> Self::Epsilon {
+        f64::EPSILON
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+        self.default_unit_values()
+            .iter()
+            .zip(other.default_unit_values().iter())
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+impl<T: VectorQuantity + Default + PartialEq> UlpsEq for Vector3<T> {
+    fn default_max_ulps() -> u32 {
+        4
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.default_unit_values()
+            .iter()
+            .zip(other.default_unit_values().iter())
+            .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1438,4 +1491,14 @@ mod tests {
         assert_relative_eq!(result_negative.y.as_newton(), -20.0);
         assert_relative_eq!(result_negative.z.as_newton(), -30.0);
     }
+
+    #[test]
+    fn test_approx_eq_across_units() {
+        let a = Vector3::new(Distance::from_km(1.0), Distance::from_m(2.0), Distance::from_m(0.3));
+        let b = Vector3::new(Distance::from_m(1000.0), Distance::from_m(2.0), Distance::from_m(0.1 + 0.2));
+        assert_ne!(a, b);
+        assert_relative_eq!(a, b);
+        approx::assert_ulps_eq!(a, b);
+        approx::assert_abs_diff_ne!(a, Vector3::new(Distance::from_m(1000.0), Distance::from_m(2.0), Distance::from_m(0.4)));
+    }
 }
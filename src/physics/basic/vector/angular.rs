@@ -36,7 +36,7 @@ impl RotationSeq {
 
     fn set_tag(&mut self, v: u8) -> Result<u8, OperationError> {
         if self.count >= 3 {
-            return Err(OperationError::new("RotationSeq:: count exceeds 3"));
+            return Err(OperationError::InvalidRotationSequence { axes: self.count as usize + 1 });
         }
         self.tag = (self.tag << 2) | v;
         self.count += 1;
@@ -45,7 +45,7 @@ impl RotationSeq {
 
     pub fn value(&self) -> Result<u8, OperationError> {
         if self.count != 3 {
-            return Err(OperationError::new("RotationSeq:: value requires 3 axes"));
+            return Err(OperationError::InvalidRotationSequence { axes: self.count as usize });
         }
         Ok(self.tag)
     }
@@ -0,0 +1,80 @@
+mod csc;
+mod csr;
+
+pub use csc::CscMatrix;
+pub use csr::CsrMatrix;
+
+use crate::dense::error::OperationError;
+use std::ops::{Add, Mul};
+
+// 压缩存储：offsets[i]..offsets[i + 1] 为第 i 个主维（CSR 为行，CSC 为列）的非零元素
+pub(crate) struct Compressed<T> {
+    pub(crate) offsets: Vec<usize>,
+    pub(crate) indices: Vec<usize>,
+    pub(crate) values: Vec<T>,
+}
+
+// 把 (主维下标, 次维下标, 值) 压缩存储，下标相同的元素相加
+pub(crate) fn compress<T>(major: usize, mut entries: Vec<(usize, usize, T)>) -> Compressed<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    entries.sort_by_key(|&(i, j, _)| (i, j));
+    let mut offsets = vec![0; major + 1];
+    let mut indices: Vec<usize> = Vec::with_capacity(entries.len());
+    let mut values: Vec<T> = Vec::with_capacity(entries.len());
+    let mut last = None;
+    for (i, j, value) in entries {
+        if last == Some((i, j)) {
+            let merged = values.last_mut().unwrap();
+            *merged = *merged + value;
+            continue;
+        }
+        last = Some((i, j));
+        offsets[i + 1] += 1;
+        indices.push(j);
+        values.push(value);
+    }
+    for i in 0..major {
+        offsets[i + 1] += offsets[i];
+    }
+    Compressed {
+        offsets,
+        indices,
+        values,
+    }
+}
+
+// 检查三元组 (行, 列, 值) 是否都在 rows×cols 范围内
+pub(crate) fn check_triplets<T>(
+    rows: usize,
+    cols: usize,
+    triplets: &[(usize, usize, T)],
+) -> Result<(), OperationError> {
+    match triplets
+        .iter()
+        .find(|&&(row, col, _)| row >= rows || col >= cols)
+    {
+        Some(&(row, col, _)) => Err(OperationError::IndexOutOfBounds {
+            index: (row, col),
+            shape: (rows, cols),
+        }),
+        None => Ok(()),
+    }
+}
+
+// 稀疏矩阵（以三元组给出）乘行优先存放的稠密矩阵 dense（width 列），结果为 rows×width 的行优先数据
+pub(crate) fn product_dense<T, I>(rows: usize, entries: I, dense: &[T], width: usize) -> Vec<T>
+where
+    T: Copy + Default + Add<Output = T> + Mul<Output = T>,
+    I: Iterator<Item = (usize, usize, T)>,
+{
+    let mut data = vec![T::default(); rows * width];
+    for (row, col, value) in entries {
+        let source = &dense[col * width..(col + 1) * width];
+        for (cell, &b) in data[row * width..(row + 1) * width].iter_mut().zip(source) {
+            *cell = *cell + value * b;
+        }
+    }
+    data
+}
@@ -0,0 +1,249 @@
+use crate::dense::error::OperationError;
+use crate::dense::{DMatrix, Matrix};
+use crate::sparse::{self, CsrMatrix};
+use std::ops::{Add, Mul};
+
+// 按列压缩（CSC）的稀疏矩阵：第 j 列的非零元素为
+// row_indices[col_offsets[j]..col_offsets[j + 1]] 与对应的 values，列内按行号升序
+#[derive(Clone, Debug, PartialEq)]
+pub struct CscMatrix<T>
+where
+    T: Copy,
+{
+    rows: usize,
+    cols: usize,
+    col_offsets: Vec<usize>,
+    row_indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T> CscMatrix<T>
+where
+    T: Copy,
+{
+    pub fn height(&self) -> usize {
+        self.rows
+    }
+
+    pub fn width(&self) -> usize {
+        self.cols
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    // 存储的元素个数（包括显式给出的 0）
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn col_offsets(&self) -> &[usize] {
+        &self.col_offsets
+    }
+
+    pub fn row_indices(&self) -> &[usize] {
+        &self.row_indices
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    // 按列遍历存储的元素，返回 (行, 列, 值)
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, T)> + '_ {
+        (0..self.cols).flat_map(move |col| {
+            (self.col_offsets[col]..self.col_offsets[col + 1])
+                .map(move |k| (self.row_indices[k], col, self.values[k]))
+        })
+    }
+
+    pub(crate) fn from_parts(
+        rows: usize,
+        cols: usize,
+        col_offsets: Vec<usize>,
+        row_indices: Vec<usize>,
+        values: Vec<T>,
+    ) -> Self {
+        CscMatrix {
+            rows,
+            cols,
+            col_offsets,
+            row_indices,
+            values,
+        }
+    }
+}
+
+impl<T> CscMatrix<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    // 由 (行, 列, 值) 三元组构造，重复位置的值相加
+    pub fn from_triplets(
+        rows: usize,
+        cols: usize,
+        triplets: &[(usize, usize, T)],
+    ) -> Result<Self, OperationError> {
+        sparse::check_triplets(rows, cols, triplets)?;
+        let entries = triplets
+            .iter()
+            .map(|&(row, col, value)| (col, row, value))
+            .collect();
+        let compressed = sparse::compress(cols, entries);
+        Ok(CscMatrix::from_parts(
+            rows,
+            cols,
+            compressed.offsets,
+            compressed.indices,
+            compressed.values,
+        ))
+    }
+
+    pub fn transpose(&self) -> CscMatrix<T> {
+        // Aᵀ 的第 i 列即 A 的第 i 行
+        let compressed = sparse::compress(self.rows, self.iter().collect());
+        CscMatrix::from_parts(
+            self.cols,
+            self.rows,
+            compressed.offsets,
+            compressed.indices,
+            compressed.values,
+        )
+    }
+
+    // 转为按行压缩存储，A 的 CSR 与 Aᵀ 的 CSC 数组相同
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        let transposed = self.transpose();
+        CsrMatrix::from_parts(
+            self.rows,
+            self.cols,
+            transposed.col_offsets,
+            transposed.row_indices,
+            transposed.values,
+        )
+    }
+}
+
+impl<T> CscMatrix<T>
+where
+    T: Copy + Default,
+{
+    // 按 (行, 列) 读取，未存储的位置为 0
+    pub fn get(&self, row: usize, col: usize) -> Option<T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        let range = self.col_offsets[col]..self.col_offsets[col + 1];
+        match self.row_indices[range.clone()].binary_search(&row) {
+            Ok(k) => Some(self.values[range.start + k]),
+            Err(_) => Some(T::default()),
+        }
+    }
+
+    pub fn to_dense(&self) -> DMatrix<T> {
+        let mut m = DMatrix::zeros(self.rows, self.cols);
+        for (row, col, value) in self.iter() {
+            m[(row, col)] = value;
+        }
+        m
+    }
+
+    // 转换为定长矩阵，尺寸必须一致
+    pub fn to_matrix<const ROWS: usize, const COLS: usize>(
+        &self,
+    ) -> Result<Matrix<ROWS, COLS, T>, OperationError>
+    where
+        T: Send + Sync,
+    {
+        self.to_dense().to_matrix()
+    }
+}
+
+impl<T> CscMatrix<T>
+where
+    T: Copy + Default + Add<Output = T> + Mul<Output = T>,
+{
+    // 稀疏 × 稠密，复杂度 O(nnz · target.width())
+    pub fn product(&self, target: &DMatrix<T>) -> Result<DMatrix<T>, OperationError> {
+        if self.cols != target.height() {
+            return Err(OperationError::DimensionMismatch {
+                expected: (self.cols, target.width()),
+                found: target.size(),
+            });
+        }
+        let data = sparse::product_dense(self.rows, self.iter(), target.as_slice(), target.width());
+        DMatrix::new(self.rows, target.width(), data)
+    }
+
+    // 与定长矩阵相乘，ROWS 必须等于稀疏矩阵的行数
+    pub fn product_matrix<const ROWS: usize, const COLS: usize, const K: usize>(
+        &self,
+        target: &Matrix<COLS, K, T>,
+    ) -> Result<Matrix<ROWS, K, T>, OperationError>
+    where
+        T: Send + Sync,
+    {
+        if self.size() != (ROWS, COLS) {
+            return Err(OperationError::DimensionMismatch {
+                expected: (ROWS, COLS),
+                found: self.size(),
+            });
+        }
+        self.product(&DMatrix::from(*target))?.to_matrix()
+    }
+}
+
+// 丢弃稠密矩阵中的 0
+impl<T> From<&DMatrix<T>> for CscMatrix<T>
+where
+    T: Copy + Default + PartialEq + Add<Output = T>,
+{
+    fn from(m: &DMatrix<T>) -> Self {
+        CsrMatrix::from(m).to_csc()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_triplets() {
+        let m =
+            CscMatrix::from_triplets(3, 4, &[(2, 1, 5.0), (0, 3, 1.0), (0, 1, 2.0), (2, 1, 1.0)])
+                .unwrap();
+        assert_eq!(m.nnz(), 3);
+        assert_eq!(m.col_offsets(), &[0, 0, 2, 2, 3]);
+        assert_eq!(m.row_indices(), &[0, 2, 0]);
+        assert_eq!(m.values(), &[2.0, 6.0, 1.0]);
+        assert_eq!(m.get(2, 1), Some(6.0));
+        assert_eq!(m.get(1, 1), Some(0.0));
+        assert_eq!(m.get(0, 4), None);
+        assert!(CscMatrix::from_triplets(2, 2, &[(2, 0, 1)]).is_err());
+    }
+
+    #[test]
+    fn test_transpose_and_dense() {
+        let dense = DMatrix::from_rows(vec![vec![0, 1, 0], vec![2, 0, 3]]).unwrap();
+        let m = CscMatrix::from(&dense);
+        assert_eq!(m.to_dense(), dense);
+        assert_eq!(m.transpose().to_dense(), dense.T());
+        assert_eq!(m.transpose().size(), (3, 2));
+        assert_eq!(m.to_csr().to_csc(), m);
+        assert_eq!(m.to_csr(), CsrMatrix::from(&dense));
+    }
+
+    #[test]
+    fn test_product() {
+        // Jᵀ·r：先转置再与列向量相乘
+        let j = CscMatrix::from_triplets(3, 2, &[(0, 0, 1.0), (1, 1, 2.0), (2, 0, -1.0)]).unwrap();
+        let r = Matrix::<3, 1, f64>::new([[1.0], [2.0], [3.0]]);
+        let g: Matrix<2, 1, f64> = j.transpose().product_matrix(&r).unwrap();
+        assert_eq!(g, Matrix::new([[-2.0], [4.0]]));
+
+        let b = DMatrix::from_rows(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        assert_eq!(j.product(&b).unwrap(), j.to_dense().product(&b).unwrap());
+        assert!(j.product(&j.to_dense()).is_err());
+    }
+}
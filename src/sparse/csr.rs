@@ -0,0 +1,278 @@
+use crate::dense::error::OperationError;
+use crate::dense::{DMatrix, Matrix};
+use crate::sparse::{self, CscMatrix};
+use std::ops::{Add, Mul};
+
+// 按行压缩（CSR）的稀疏矩阵：第 i 行的非零元素为
+// col_indices[row_offsets[i]..row_offsets[i + 1]] 与对应的 values，行内按列号升序
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsrMatrix<T>
+where
+    T: Copy,
+{
+    rows: usize,
+    cols: usize,
+    row_offsets: Vec<usize>,
+    col_indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T> CsrMatrix<T>
+where
+    T: Copy,
+{
+    pub fn height(&self) -> usize {
+        self.rows
+    }
+
+    pub fn width(&self) -> usize {
+        self.cols
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    // 存储的元素个数（包括显式给出的 0）
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn row_offsets(&self) -> &[usize] {
+        &self.row_offsets
+    }
+
+    pub fn col_indices(&self) -> &[usize] {
+        &self.col_indices
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    // 按行遍历存储的元素，返回 (行, 列, 值)
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, T)> + '_ {
+        (0..self.rows).flat_map(move |row| {
+            (self.row_offsets[row]..self.row_offsets[row + 1])
+                .map(move |k| (row, self.col_indices[k], self.values[k]))
+        })
+    }
+}
+
+impl<T> CsrMatrix<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    // 由 (行, 列, 值) 三元组构造，重复位置的值相加
+    pub fn from_triplets(
+        rows: usize,
+        cols: usize,
+        triplets: &[(usize, usize, T)],
+    ) -> Result<Self, OperationError> {
+        sparse::check_triplets(rows, cols, triplets)?;
+        let compressed = sparse::compress(rows, triplets.to_vec());
+        Ok(CsrMatrix {
+            rows,
+            cols,
+            row_offsets: compressed.offsets,
+            col_indices: compressed.indices,
+            values: compressed.values,
+        })
+    }
+
+    pub fn transpose(&self) -> CsrMatrix<T> {
+        let compressed = sparse::compress(
+            self.cols,
+            self.iter()
+                .map(|(row, col, value)| (col, row, value))
+                .collect(),
+        );
+        CsrMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            row_offsets: compressed.offsets,
+            col_indices: compressed.indices,
+            values: compressed.values,
+        }
+    }
+
+    // 转为按列压缩存储，A 的 CSC 与 Aᵀ 的 CSR 数组相同
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        let transposed = self.transpose();
+        CscMatrix::from_parts(
+            self.rows,
+            self.cols,
+            transposed.row_offsets,
+            transposed.col_indices,
+            transposed.values,
+        )
+    }
+
+    pub(crate) fn from_parts(
+        rows: usize,
+        cols: usize,
+        row_offsets: Vec<usize>,
+        col_indices: Vec<usize>,
+        values: Vec<T>,
+    ) -> Self {
+        CsrMatrix {
+            rows,
+            cols,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+}
+
+impl<T> CsrMatrix<T>
+where
+    T: Copy + Default,
+{
+    // 按 (行, 列) 读取，未存储的位置为 0
+    pub fn get(&self, row: usize, col: usize) -> Option<T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        let range = self.row_offsets[row]..self.row_offsets[row + 1];
+        match self.col_indices[range.clone()].binary_search(&col) {
+            Ok(k) => Some(self.values[range.start + k]),
+            Err(_) => Some(T::default()),
+        }
+    }
+
+    pub fn to_dense(&self) -> DMatrix<T> {
+        let mut m = DMatrix::zeros(self.rows, self.cols);
+        for (row, col, value) in self.iter() {
+            m[(row, col)] = value;
+        }
+        m
+    }
+
+    // 转换为定长矩阵，尺寸必须一致
+    pub fn to_matrix<const ROWS: usize, const COLS: usize>(
+        &self,
+    ) -> Result<Matrix<ROWS, COLS, T>, OperationError>
+    where
+        T: Send + Sync,
+    {
+        self.to_dense().to_matrix()
+    }
+}
+
+impl<T> CsrMatrix<T>
+where
+    T: Copy + Default + Add<Output = T> + Mul<Output = T>,
+{
+    // 稀疏 × 稠密，复杂度 O(nnz · target.width())
+    pub fn product(&self, target: &DMatrix<T>) -> Result<DMatrix<T>, OperationError> {
+        if self.cols != target.height() {
+            return Err(OperationError::DimensionMismatch {
+                expected: (self.cols, target.width()),
+                found: target.size(),
+            });
+        }
+        let data = sparse::product_dense(self.rows, self.iter(), target.as_slice(), target.width());
+        DMatrix::new(self.rows, target.width(), data)
+    }
+
+    // 与定长矩阵相乘，ROWS 必须等于稀疏矩阵的行数
+    pub fn product_matrix<const ROWS: usize, const COLS: usize, const K: usize>(
+        &self,
+        target: &Matrix<COLS, K, T>,
+    ) -> Result<Matrix<ROWS, K, T>, OperationError>
+    where
+        T: Send + Sync,
+    {
+        if self.size() != (ROWS, COLS) {
+            return Err(OperationError::DimensionMismatch {
+                expected: (ROWS, COLS),
+                found: self.size(),
+            });
+        }
+        self.product(&DMatrix::from(*target))?.to_matrix()
+    }
+}
+
+// 丢弃稠密矩阵中的 0
+impl<T> From<&DMatrix<T>> for CsrMatrix<T>
+where
+    T: Copy + Default + PartialEq + Add<Output = T>,
+{
+    fn from(m: &DMatrix<T>) -> Self {
+        let mut triplets = Vec::new();
+        for (row, line) in m.rows().enumerate() {
+            for (col, &value) in line.iter().enumerate() {
+                if value != T::default() {
+                    triplets.push((row, col, value));
+                }
+            }
+        }
+        let compressed = sparse::compress(m.height(), triplets);
+        CsrMatrix::from_parts(
+            m.height(),
+            m.width(),
+            compressed.offsets,
+            compressed.indices,
+            compressed.values,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_triplets() {
+        let m =
+            CsrMatrix::from_triplets(3, 4, &[(2, 1, 5.0), (0, 3, 1.0), (0, 0, 2.0), (2, 1, 1.0)])
+                .unwrap();
+        assert_eq!(m.size(), (3, 4));
+        assert_eq!(m.nnz(), 3);
+        assert_eq!(m.row_offsets(), &[0, 2, 2, 3]);
+        assert_eq!(m.col_indices(), &[0, 3, 1]);
+        assert_eq!(m.values(), &[2.0, 1.0, 6.0]);
+        assert_eq!(m.get(2, 1), Some(6.0));
+        assert_eq!(m.get(1, 1), Some(0.0));
+        assert_eq!(m.get(3, 0), None);
+
+        let err = CsrMatrix::from_triplets(2, 2, &[(0, 0, 1), (1, 2, 1)]).unwrap_err();
+        assert_eq!(
+            err,
+            OperationError::IndexOutOfBounds {
+                index: (1, 2),
+                shape: (2, 2)
+            }
+        );
+    }
+
+    #[test]
+    fn test_transpose_and_dense() {
+        let m = CsrMatrix::from_triplets(2, 3, &[(0, 1, 1), (1, 0, 2), (1, 2, 3)]).unwrap();
+        let dense = DMatrix::from_rows(vec![vec![0, 1, 0], vec![2, 0, 3]]).unwrap();
+        assert_eq!(m.to_dense(), dense);
+        assert_eq!(m.transpose().to_dense(), dense.T());
+        assert_eq!(m.transpose().transpose(), m);
+        assert_eq!(CsrMatrix::from(&dense), m);
+        assert_eq!(m.to_csc().to_csr(), m);
+        assert_eq!(
+            m.to_matrix::<2, 3>().unwrap(),
+            Matrix::new([[0, 1, 0], [2, 0, 3]])
+        );
+        assert!(m.to_matrix::<3, 2>().is_err());
+    }
+
+    #[test]
+    fn test_product() {
+        let m = CsrMatrix::from_triplets(2, 3, &[(0, 1, 1.0), (1, 0, 2.0), (1, 2, 3.0)]).unwrap();
+        let b = DMatrix::from_rows(vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]).unwrap();
+        assert_eq!(m.product(&b).unwrap(), m.to_dense().product(&b).unwrap());
+        assert!(m.product(&m.to_dense()).is_err());
+
+        let x = Matrix::<3, 1, f64>::new([[1.0], [2.0], [3.0]]);
+        let y: Matrix<2, 1, f64> = m.product_matrix(&x).unwrap();
+        assert_eq!(y, Matrix::new([[2.0], [11.0]]));
+        assert!(m.transpose().product_matrix::<3, 3, 1>(&x).is_err());
+    }
+}
@@ -4,9 +4,11 @@ use crate::physics::basic::vector::angular::{RotationSeq, TAG_X, TAG_Y, TAG_Z};
 use crate::physics::basic::{Angular, Coef, PhysicalQuantity, Vector3, VectorQuantity};
 use crate::spatial_geometry::quaternion::Quaternion;
 use crate::utils::float::{sgn, sgn2_64};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 use std::ops::{Add, Div, Mul, Sub};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CosMatrix {
     data: [f64; 9],
 }
@@ -600,6 +602,41 @@ impl CosMatrix {
     }
 }
 
+impl AbsDiffEq for CosMatrix {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        f64::EPSILON
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.data.iter().zip(other.data.iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl RelativeEq for CosMatrix {
+    fn default_max_relative() -> Self::Epsilon {
+        f64::EPSILON
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+        self.data.iter().zip(other.data.iter())
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+impl UlpsEq for CosMatrix {
+    fn default_max_ulps() -> u32 {
+        4
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.data.iter().zip(other.data.iter())
+            .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1388,4 +1425,14 @@ mod tests {
 
         println!("\n=== 所有to_euler测试通过！ ===");
     }
+
+    #[test]
+    fn test_eq_and_approx_eq() {
+        let data = [[0.0, 1.0, 0.0], [-1.0, 0.0, 0.0], [0.0, 0.0, 1.0]];
+        assert_eq!(CosMatrix::new(data), CosMatrix::new(data));
+        let perturbed = CosMatrix::new([[1e-17, 1.0, 0.0], [-1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+        assert_ne!(CosMatrix::new(data), perturbed);
+        approx::assert_abs_diff_eq!(CosMatrix::new(data), perturbed);
+        approx::assert_relative_ne!(CosMatrix::new(data), CosMatrix::default());
+    }
 }
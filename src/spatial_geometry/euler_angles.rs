@@ -5,6 +5,7 @@ use crate::physics::basic::{Angular, AngularType, Coef, Vector3};
 use crate::spatial_geometry::cos_matrix::CosMatrix;
 use crate::spatial_geometry::quaternion::Quaternion;
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EulerAngles {
     pub yaw: Angular,
     pub pitch: Angular,
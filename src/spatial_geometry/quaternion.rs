@@ -4,9 +4,11 @@ use crate::dense::error::OperationError;
 use crate::dense::Matrix;
 use crate::physics::basic::{Angular, Coef, Vector3};
 use crate::spatial_geometry::cos_matrix::CosMatrix;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 use std::ops::{Add, Div, Mul};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Quaternion {
     pub q0: f64, // 实部
     pub q1: f64, //x
@@ -135,15 +137,17 @@ impl Quaternion {
 
     pub fn to_axis(&self, angle: Angular) -> Result<Vector3<Coef>, OperationError> {
         if self.norm().abs() < 1e-12 {
-            return Err(OperationError {
-                message: "quaternion norm is zero ,could not get axis".to_string(),
+            return Err(OperationError::InvalidValue {
+                name: "quaternion norm",
+                value: self.norm(),
             });
         }
         let q = self.normalize();
         let half_angle = angle / 2.0;
         if half_angle.sin().abs() < 1e-12 {
-            return Err(OperationError {
-                message: "angle is zero ,could not get axis".to_string(),
+            return Err(OperationError::InvalidValue {
+                name: "rotation angle",
+                value: angle.as_rad(),
             });
         }
         let x = q.q1 / half_angle.sin();
@@ -297,6 +301,47 @@ impl Mul for &Quaternion {
     }
 }
 
+impl AbsDiffEq for Quaternion {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        f64::EPSILON
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        [self.q0, self.q1, self.q2, self.q3]
+            .iter()
+            .zip([other.q0, other.q1, other.q2, other.q3].iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl RelativeEq for Quaternion {
+    fn default_max_relative() -> Self::Epsilon {
+        f64::EPSILON
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+        [self.q0, self.q1, self.q2, self.q3]
+            .iter()
+            .zip([other.q0, other.q1, other.q2, other.q3].iter())
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+impl UlpsEq for Quaternion {
+    fn default_max_ulps() -> u32 {
+        4
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        [self.q0, self.q1, self.q2, self.q3]
+            .iter()
+            .zip([other.q0, other.q1, other.q2, other.q3].iter())
+            .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -640,14 +685,26 @@ mod tests {
         let result = zero_q.to_axis(angle);
         assert!(result.is_err());
         let err = result.unwrap_err();
-        assert_eq!(err.message, "quaternion norm is zero ,could not get axis");
+        assert_eq!(
+            err,
+            OperationError::InvalidValue {
+                name: "quaternion norm",
+                value: 0.0
+            }
+        );
 
         let angle = Angular::from_rad(0.0);
         let q = Quaternion::default();
         let result = q.to_axis(angle);
         assert!(result.is_err());
         let err = result.unwrap_err();
-        assert_eq!(err.message, "angle is zero ,could not get axis");
+        assert_eq!(
+            err,
+            OperationError::InvalidValue {
+                name: "rotation angle",
+                value: 0.0
+            }
+        );
     }
 
     #[test]
@@ -748,4 +805,37 @@ mod tests {
         println!("y = {:.16}", y_manual);
         println!("z = {:.16}", z_manual);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use crate::physics::basic::AngularType;
+        use crate::spatial_geometry::euler_angles::EulerAngles;
+
+        let q = Quaternion::new(0.5, 0.5, -0.5, 0.5);
+        let json = serde_json::to_string(&q).unwrap();
+        assert_eq!(json, r#"{"q0":0.5,"q1":0.5,"q2":-0.5,"q3":0.5}"#);
+        assert_eq!(serde_json::from_str::<Quaternion>(&json).unwrap(), q);
+
+        let data = [[0.0, 1.0, 0.0], [-1.0, 0.0, 0.0], [0.0, 0.0, 1.0]];
+        let c = CosMatrix::new(data);
+        let back: CosMatrix = serde_json::from_str(&serde_json::to_string(&c).unwrap()).unwrap();
+        assert_eq!(back.to_array(), data);
+
+        let e = EulerAngles::from_array([10.0, 20.0, 30.0], AngularType::Deg);
+        let back: EulerAngles = serde_json::from_str(&serde_json::to_string(&e).unwrap()).unwrap();
+        assert_eq!(back.yaw, e.yaw);
+        assert_eq!(back.pitch, e.pitch);
+        assert_eq!(back.roll, e.roll);
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let a = Quaternion::new(0.1 + 0.2, 0.5, -0.5, 0.5);
+        let b = Quaternion::new(0.3, 0.5, -0.5, 0.5);
+        assert_ne!(a, b);
+        assert_relative_eq!(a, b);
+        approx::assert_ulps_eq!(a, b);
+        approx::assert_abs_diff_ne!(a, Quaternion::new(0.3, 0.5, -0.5, 0.51), epsilon = 1e-3);
+    }
 }